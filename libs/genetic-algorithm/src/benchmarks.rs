@@ -0,0 +1,113 @@
+mod ackley;
+mod knapsack;
+mod one_max;
+mod rastrigin;
+mod rosenbrock;
+mod schwefel;
+mod sphere;
+mod trap;
+
+pub use self::ackley::*;
+pub use self::knapsack::*;
+pub use self::one_max::*;
+pub use self::rastrigin::*;
+pub use self::rosenbrock::*;
+pub use self::schwefel::*;
+pub use self::sphere::*;
+pub use self::trap::*;
+use crate::*;
+use std::f32::consts::PI;
+
+/// Optimization problem with a known optimum, used to regression-test the
+/// genetic operators.
+///
+/// Minimization problems report `1 / (1 + f(x))` as their fitness, so that
+/// they can be maximized like everything else and reach `1.0` at the optimum.
+pub trait Benchmark: Individual {
+    fn bounds(dimensions: usize) -> Bounds;
+    fn optimum(dimensions: usize) -> Chromosome;
+    fn max_fitness(dimensions: usize) -> f32;
+}
+
+fn minimized(value: f32) -> f32 {
+    1.0 / (1.0 + value)
+}
+
+fn bits(chromosome: &Chromosome) -> impl Iterator<Item = bool> + '_ {
+    chromosome.iter().map(|&gene| gene >= 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn random<B>(rng: &mut dyn RngCore, dimensions: usize) -> B where B: Benchmark {
+        let bounds = B::bounds(dimensions);
+
+        B::create(
+            (0..dimensions)
+                .map(|gene| {
+                    let (lower, upper) = bounds.range(gene);
+                    rng.gen_range(lower..=upper)
+                })
+                .collect(),
+        )
+    }
+
+    fn best_fitness<B>(dimensions: usize, generations: usize) -> f32 where B: Benchmark {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection,
+            UniformCrossover,
+            GaussianMutation::new(0.1, 0.5),
+        )
+        .with_bounds(B::bounds(dimensions));
+
+        let mut population: Vec<B> = (0..50).map(|_| random(&mut rng, dimensions)).collect();
+        let mut best = 0.0_f32;
+
+        for generation in 0..generations {
+            let (new_population, stats) = ga.evolve(&mut rng, &population, generation);
+
+            best = best.max(stats.max_fitness);
+            population = new_population;
+        }
+
+        best
+    }
+
+    #[test]
+    fn optima() {
+        fn check<B>(dimensions: usize) where B: Benchmark {
+            let optimum = B::create(B::optimum(dimensions));
+
+            assert!(B::bounds(dimensions).contains(optimum.chromosome()));
+            approx::assert_relative_eq!(optimum.fitness(), B::max_fitness(dimensions), epsilon = 1e-3);
+
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            for _ in 0..100 {
+                assert!(random::<B>(&mut rng, dimensions).fitness() <= optimum.fitness());
+            }
+        }
+
+        check::<Ackley>(5);
+        check::<Knapsack>(10);
+        check::<OneMax>(12);
+        check::<Rastrigin>(5);
+        check::<Rosenbrock>(5);
+        check::<Schwefel>(5);
+        check::<Sphere>(5);
+        check::<Trap>(12);
+    }
+
+    #[test]
+    fn genetic_algorithm_on_benchmarks() {
+        assert!(best_fitness::<Sphere>(5, 100) > 0.95);
+        assert!(best_fitness::<OneMax>(20, 100) >= 19.0);
+        assert!(best_fitness::<Knapsack>(10, 100) >= 300.0);
+    }
+}
@@ -0,0 +1,54 @@
+use super::*;
+use std::f32::consts::E;
+
+#[derive(Clone, Debug)]
+pub struct Ackley {
+    chromosome: Chromosome,
+}
+
+impl Individual for Ackley {
+    fn create(chromosome: Chromosome) -> Self {
+        Self { chromosome }
+    }
+
+    fn fitness(&self) -> f32 {
+        let n = self.chromosome.len() as f32;
+        let squares = self.chromosome.iter().map(|x| x * x).sum::<f32>();
+        let cosines = self.chromosome.iter().map(|x| (2.0 * PI * x).cos()).sum::<f32>();
+
+        let value = -20.0 * (-0.2 * (squares / n).sqrt()).exp() - (cosines / n).exp() + 20.0 + E;
+
+        minimized(value.max(0.0))
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+}
+
+impl Benchmark for Ackley {
+    fn bounds(_dimensions: usize) -> Bounds {
+        Bounds::uniform(-32.768, 32.768)
+    }
+
+    fn optimum(dimensions: usize) -> Chromosome {
+        vec![0.0; dimensions].into_iter().collect()
+    }
+
+    fn max_fitness(_dimensions: usize) -> f32 {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn ackley() {
+        let ackley = Ackley::create(vec![1.0, 1.0].into_iter().collect());
+
+        assert_relative_eq!(ackley.fitness(), 1.0 / (1.0 + 3.6253848), epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,83 @@
+use super::*;
+
+// Instance P01 from the classic 0/1 knapsack test set; picking the first
+// four items together with the sixth one fills the knapsack exactly and is
+// worth 309.
+const WEIGHTS: [f32; 10] = [23.0, 31.0, 29.0, 44.0, 53.0, 38.0, 63.0, 85.0, 89.0, 82.0];
+const VALUES: [f32; 10] = [92.0, 57.0, 49.0, 68.0, 60.0, 43.0, 67.0, 84.0, 87.0, 72.0];
+const CAPACITY: f32 = 165.0;
+const OPTIMUM: f32 = 309.0;
+
+// Weights are whole numbers, so an overweight pick is at least one unit over
+// and even taking every item ends up well below the optimum
+const OVERWEIGHT_PENALTY: f32 = 10.0;
+
+#[derive(Clone, Debug)]
+pub struct Knapsack {
+    chromosome: Chromosome,
+}
+
+impl Individual for Knapsack {
+    fn create(chromosome: Chromosome) -> Self {
+        assert_eq!(chromosome.len(), WEIGHTS.len());
+
+        Self { chromosome }
+    }
+
+    fn fitness(&self) -> f32 {
+        let (weight, value) = bits(&self.chromosome)
+            .zip(WEIGHTS.iter().zip(VALUES.iter()))
+            .filter(|(bit, _)| *bit)
+            .fold((0.0, 0.0), |(weight, value), (_, (w, v))| (weight + w, value + v));
+
+        Penalty::new(OVERWEIGHT_PENALTY).apply(value, [weight - CAPACITY])
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+}
+
+impl Benchmark for Knapsack {
+    fn bounds(dimensions: usize) -> Bounds {
+        assert_eq!(dimensions, WEIGHTS.len());
+
+        Bounds::uniform(0.0, 1.0)
+    }
+
+    fn optimum(dimensions: usize) -> Chromosome {
+        assert_eq!(dimensions, WEIGHTS.len());
+
+        vec![1.0, 1.0, 1.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0].into_iter().collect()
+    }
+
+    fn max_fitness(dimensions: usize) -> f32 {
+        assert_eq!(dimensions, WEIGHTS.len());
+
+        OPTIMUM
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optimum_is_the_best_of_all_picks() {
+        let best = (0..1u32 << WEIGHTS.len())
+            .map(|picks| {
+                Knapsack::create((0..WEIGHTS.len()).map(|item| (picks >> item & 1) as f32).collect())
+                    .fitness()
+            })
+            .fold(0.0, f32::max);
+
+        assert_eq!(best, OPTIMUM);
+    }
+
+    #[test]
+    fn overweight_picks_are_penalized() {
+        let knapsack = Knapsack::create(vec![1.0; 10].into_iter().collect());
+
+        approx::assert_relative_eq!(knapsack.fitness(), 679.0 / 3721.0);
+    }
+}
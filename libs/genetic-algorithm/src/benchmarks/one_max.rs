@@ -0,0 +1,46 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+pub struct OneMax {
+    chromosome: Chromosome,
+}
+
+impl Individual for OneMax {
+    fn create(chromosome: Chromosome) -> Self {
+        Self { chromosome }
+    }
+
+    fn fitness(&self) -> f32 {
+        bits(&self.chromosome).filter(|&bit| bit).count() as f32
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+}
+
+impl Benchmark for OneMax {
+    fn bounds(_dimensions: usize) -> Bounds {
+        Bounds::uniform(0.0, 1.0)
+    }
+
+    fn optimum(dimensions: usize) -> Chromosome {
+        vec![1.0; dimensions].into_iter().collect()
+    }
+
+    fn max_fitness(dimensions: usize) -> f32 {
+        dimensions as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_max() {
+        let one_max = OneMax::create(vec![0.9, 0.1, 0.5, 0.49].into_iter().collect());
+
+        assert_eq!(one_max.fitness(), 2.0);
+    }
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+pub struct Rastrigin {
+    chromosome: Chromosome,
+}
+
+impl Individual for Rastrigin {
+    fn create(chromosome: Chromosome) -> Self {
+        Self { chromosome }
+    }
+
+    fn fitness(&self) -> f32 {
+        let value = self
+            .chromosome
+            .iter()
+            .map(|x| x * x - 10.0 * (2.0 * PI * x).cos())
+            .sum::<f32>();
+
+        minimized(10.0 * self.chromosome.len() as f32 + value)
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+}
+
+impl Benchmark for Rastrigin {
+    fn bounds(_dimensions: usize) -> Bounds {
+        Bounds::uniform(-5.12, 5.12)
+    }
+
+    fn optimum(dimensions: usize) -> Chromosome {
+        vec![0.0; dimensions].into_iter().collect()
+    }
+
+    fn max_fitness(_dimensions: usize) -> f32 {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rastrigin() {
+        // Every integer point is a local optimum worth `x^2` per gene
+        let rastrigin = Rastrigin::create(vec![1.0, -2.0].into_iter().collect());
+
+        assert_relative_eq!(rastrigin.fitness(), 1.0 / 6.0, epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+pub struct Rosenbrock {
+    chromosome: Chromosome,
+}
+
+impl Individual for Rosenbrock {
+    fn create(chromosome: Chromosome) -> Self {
+        Self { chromosome }
+    }
+
+    fn fitness(&self) -> f32 {
+        let genes: Vec<_> = self.chromosome.iter().copied().collect();
+
+        let value = genes
+            .windows(2)
+            .map(|pair| {
+                let (x, y) = (pair[0], pair[1]);
+
+                100.0 * (y - x * x).powi(2) + (1.0 - x).powi(2)
+            })
+            .sum();
+
+        minimized(value)
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+}
+
+impl Benchmark for Rosenbrock {
+    fn bounds(_dimensions: usize) -> Bounds {
+        Bounds::uniform(-5.0, 10.0)
+    }
+
+    fn optimum(dimensions: usize) -> Chromosome {
+        vec![1.0; dimensions].into_iter().collect()
+    }
+
+    fn max_fitness(_dimensions: usize) -> f32 {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rosenbrock() {
+        let rosenbrock = Rosenbrock::create(vec![0.0, 0.0, 1.0].into_iter().collect());
+
+        assert_relative_eq!(rosenbrock.fitness(), 1.0 / 103.0);
+    }
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+const SCHWEFEL_OPTIMUM: f32 = 420.96875;
+
+#[derive(Clone, Debug)]
+pub struct Schwefel {
+    chromosome: Chromosome,
+}
+
+impl Individual for Schwefel {
+    fn create(chromosome: Chromosome) -> Self {
+        Self { chromosome }
+    }
+
+    fn fitness(&self) -> f32 {
+        let value = self
+            .chromosome
+            .iter()
+            .map(|x| x * x.abs().sqrt().sin())
+            .sum::<f32>();
+
+        minimized((418.9829 * self.chromosome.len() as f32 - value).max(0.0))
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+}
+
+impl Benchmark for Schwefel {
+    fn bounds(_dimensions: usize) -> Bounds {
+        Bounds::uniform(-500.0, 500.0)
+    }
+
+    fn optimum(dimensions: usize) -> Chromosome {
+        vec![SCHWEFEL_OPTIMUM; dimensions].into_iter().collect()
+    }
+
+    fn max_fitness(_dimensions: usize) -> f32 {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn schwefel() {
+        let schwefel = Schwefel::create(vec![0.0, 0.0].into_iter().collect());
+
+        assert_relative_eq!(schwefel.fitness(), 1.0 / (1.0 + 2.0 * 418.9829));
+    }
+}
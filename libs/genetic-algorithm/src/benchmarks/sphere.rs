@@ -0,0 +1,47 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+pub struct Sphere {
+    chromosome: Chromosome,
+}
+
+impl Individual for Sphere {
+    fn create(chromosome: Chromosome) -> Self {
+        Self { chromosome }
+    }
+
+    fn fitness(&self) -> f32 {
+        minimized(self.chromosome.iter().map(|x| x * x).sum())
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+}
+
+impl Benchmark for Sphere {
+    fn bounds(_dimensions: usize) -> Bounds {
+        Bounds::uniform(-5.12, 5.12)
+    }
+
+    fn optimum(dimensions: usize) -> Chromosome {
+        vec![0.0; dimensions].into_iter().collect()
+    }
+
+    fn max_fitness(_dimensions: usize) -> f32 {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn sphere() {
+        let sphere = Sphere::create(vec![1.0, -2.0, 0.5].into_iter().collect());
+
+        assert_relative_eq!(sphere.fitness(), 1.0 / 6.25);
+    }
+}
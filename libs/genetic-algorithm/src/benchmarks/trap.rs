@@ -0,0 +1,68 @@
+use super::*;
+
+const TRAP_SIZE: usize = 4;
+
+/// Deceptive trap: every block of `TRAP_SIZE` bits pays off fully only when
+/// all of its bits are set, while the gradient inside the block leads
+/// towards all zeros.
+#[derive(Clone, Debug)]
+pub struct Trap {
+    chromosome: Chromosome,
+}
+
+impl Individual for Trap {
+    fn create(chromosome: Chromosome) -> Self {
+        Self { chromosome }
+    }
+
+    fn fitness(&self) -> f32 {
+        let bits: Vec<_> = bits(&self.chromosome).collect();
+
+        bits.chunks(TRAP_SIZE)
+            .map(|block| {
+                let ones = block.iter().filter(|&&bit| bit).count();
+
+                if ones == block.len() {
+                    block.len()
+                } else {
+                    block.len() - 1 - ones
+                }
+            })
+            .sum::<usize>() as f32
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+}
+
+impl Benchmark for Trap {
+    fn bounds(_dimensions: usize) -> Bounds {
+        Bounds::uniform(0.0, 1.0)
+    }
+
+    fn optimum(dimensions: usize) -> Chromosome {
+        vec![1.0; dimensions].into_iter().collect()
+    }
+
+    fn max_fitness(dimensions: usize) -> f32 {
+        dimensions as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fitness(bits: &[f32]) -> f32 {
+        Trap::create(bits.iter().cloned().collect()).fitness()
+    }
+
+    #[test]
+    fn trap() {
+        assert_eq!(fitness(&[1.0, 1.0, 1.0, 1.0]), 4.0);
+        assert_eq!(fitness(&[0.0, 0.0, 0.0, 0.0]), 3.0);
+        assert_eq!(fitness(&[1.0, 1.0, 1.0, 0.0]), 0.0);
+        assert_eq!(fitness(&[1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0]), 6.0);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawBounds"))]
+pub struct Bounds {
+    ranges: Ranges,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawBounds {
+    ranges: Ranges,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawBounds> for Bounds {
+    type Error = GaError;
+
+    fn try_from(raw: RawBounds) -> Result<Self, Self::Error> {
+        raw.ranges.validate()?;
+
+        Ok(Self { ranges: raw.ranges })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Ranges {
+    Uniform(f32, f32),
+    PerGene(Vec<(f32, f32)>),
+}
+
+impl Bounds {
+    pub fn uniform(lower: f32, upper: f32) -> Self {
+        let ranges = Ranges::Uniform(lower, upper);
+
+        ranges.validate().unwrap_or_else(|err| panic!("{}", err));
+
+        Self { ranges }
+    }
+
+    pub fn per_gene(ranges: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        let ranges = Ranges::PerGene(ranges.into_iter().collect());
+
+        ranges.validate().unwrap_or_else(|err| panic!("{}", err));
+
+        Self { ranges }
+    }
+
+    pub fn range(&self, gene: usize) -> (f32, f32) {
+        match &self.ranges {
+            Ranges::Uniform(lower, upper) => (*lower, *upper),
+            Ranges::PerGene(ranges) => ranges[gene],
+        }
+    }
+
+    pub fn contains(&self, chromosome: &Chromosome) -> bool {
+        self.check(chromosome);
+
+        chromosome.iter().enumerate().all(|(gene, value)| {
+            let (lower, upper) = self.range(gene);
+
+            (lower..=upper).contains(value)
+        })
+    }
+
+    pub fn violations<'a>(&'a self, chromosome: &'a Chromosome) -> impl Iterator<Item = f32> + 'a {
+        self.check(chromosome);
+
+        chromosome.iter().enumerate().map(|(gene, value)| {
+            let (lower, upper) = self.range(gene);
+
+            (lower - value).max(value - upper).max(0.0)
+        })
+    }
+
+    pub(crate) fn check(&self, chromosome: &Chromosome) {
+        self.try_check(chromosome).unwrap_or_else(|err| panic!("{}", err));
+    }
+
+    pub(crate) fn try_check(&self, chromosome: &Chromosome) -> Result<(), GaError> {
+        self.try_check_len(chromosome.len())
+    }
+
+    pub fn try_check_len(&self, genes: usize) -> Result<(), GaError> {
+        match &self.ranges {
+            Ranges::PerGene(ranges) if ranges.len() != genes => Err(GaError::LengthMismatch {
+                expected: ranges.len(),
+                actual: genes,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Ranges {
+    fn validate(&self) -> Result<(), GaError> {
+        let valid = match self {
+            Self::Uniform(lower, upper) => lower <= upper,
+            Self::PerGene(ranges) => ranges.iter().all(|(lower, upper)| lower <= upper),
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GaError::InvalidConfig("lower bound must not exceed upper bound"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_bounds() {
+        let bounds = Bounds::uniform(-1.0, 1.0);
+
+        assert_eq!(bounds.range(0), (-1.0, 1.0));
+        assert_eq!(bounds.range(100), (-1.0, 1.0));
+        assert!(bounds.contains(&vec![-1.0, 0.0, 1.0].into_iter().collect()));
+        assert!(!bounds.contains(&vec![-1.0, 0.0, 1.5].into_iter().collect()));
+    }
+
+    #[test]
+    fn per_gene_bounds() {
+        let bounds = Bounds::per_gene([(0.0, 1.0), (-5.0, 5.0)]);
+
+        assert_eq!(bounds.range(1), (-5.0, 5.0));
+        assert!(bounds.contains(&vec![0.5, -4.0].into_iter().collect()));
+        assert!(!bounds.contains(&vec![-0.5, -4.0].into_iter().collect()));
+    }
+
+    #[test]
+    fn violations() {
+        let bounds = Bounds::uniform(-1.0, 1.0);
+        let chromosome = vec![-1.5, 0.0, 3.0].into_iter().collect();
+        let actual: Vec<_> = bounds.violations(&chromosome).collect();
+
+        assert_eq!(actual, vec![0.5, 0.0, 2.0]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates() {
+        let bounds: Bounds = serde_json::from_str(r#"{"ranges":{"PerGene":[[0.0,1.0],[-5.0,5.0]]}}"#).unwrap();
+
+        assert_eq!(bounds.range(1), (-5.0, 5.0));
+        assert!(serde_json::from_str::<Bounds>(r#"{"ranges":{"Uniform":[1.0,-1.0]}}"#).is_err());
+        assert!(serde_json::from_str::<Bounds>(r#"{"ranges":{"PerGene":[[0.0,1.0],[5.0,-5.0]]}}"#).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn per_gene_bounds_with_mismatched_length() {
+        Bounds::per_gene([(0.0, 1.0)]).contains(&vec![0.5, 0.5].into_iter().collect());
+    }
+}
@@ -0,0 +1,310 @@
+use crate::*;
+use rand::seq::index;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OpponentSampling {
+    /// Every individual meets every opponent.
+    All,
+    /// Every individual meets its own random sample of opponents.
+    Random(usize),
+    /// One random sample of opponents is drawn per generation and shared by
+    /// the whole population.
+    Panel(usize),
+}
+
+/// Evolves two populations side by side, where fitness comes from pairwise
+/// interactions between members of the opposing populations (host-parasite,
+/// predator-prey and so on).
+pub struct Coevolution<SA, SB> {
+    ga_a: GeneticAlgorithm<SA>,
+    ga_b: GeneticAlgorithm<SB>,
+    sampling: OpponentSampling,
+}
+
+impl<SA, SB> Coevolution<SA, SB> where SA: SelectionMethod, SB: SelectionMethod {
+    pub fn new(ga_a: GeneticAlgorithm<SA>, ga_b: GeneticAlgorithm<SB>, sampling: OpponentSampling) -> Self {
+        Self { ga_a, ga_b, sampling }
+    }
+
+    /// `interact` plays one individual of each population against the other
+    /// and returns their scores; an individual's fitness is its average score
+    /// over the opponents it has met.
+    ///
+    /// Candidates proposed by local search are all scored against one set of
+    /// opponents drawn the same way: everyone under `All`, the shared panel
+    /// under `Panel` and a fresh sample under `Random`.
+    pub fn evolve<A, B>(
+        &self,
+        rng: &mut dyn RngCore,
+        population_a: &[A],
+        population_b: &[B],
+        generation: usize,
+        interact: &mut dyn FnMut(&A, &B) -> (f32, f32),
+    ) -> ((Vec<A>, Statistics), (Vec<B>, Statistics)) where A: Individual, B: Individual {
+        self.try_evolve(rng, population_a, population_b, generation, interact)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn try_evolve<A, B>(
+        &self,
+        rng: &mut dyn RngCore,
+        population_a: &[A],
+        population_b: &[B],
+        generation: usize,
+        interact: &mut dyn FnMut(&A, &B) -> (f32, f32),
+    ) -> Result<((Vec<A>, Statistics), (Vec<B>, Statistics)), GaError> where A: Individual, B: Individual {
+        if population_a.is_empty() || population_b.is_empty() {
+            return Err(GaError::EmptyPopulation);
+        }
+
+        let opponents_of_a = self.sample(rng, population_a.len(), population_b.len());
+        let opponents_of_b = self.sample(rng, population_b.len(), population_a.len());
+        let candidate_opponents_a = self.candidate_opponents(rng, &opponents_of_a, population_b.len());
+        let candidate_opponents_b = self.candidate_opponents(rng, &opponents_of_b, population_a.len());
+
+        // Retried interactions replace their cached score, so the opponent sees it too
+        let mut scores = HashMap::new();
+        let mut score = |a: usize, b: usize, retry: bool| {
+            if retry {
+                scores.remove(&(a, b));
+            }
+
+            *scores
+                .entry((a, b))
+                .or_insert_with(|| interact(&population_a[a], &population_b[b]))
+        };
+
+        let mut fitness_a = |a: usize, retry: bool| average(opponents_of_a[a].iter().map(|&b| score(a, b, retry).0));
+        let mut fitnesses_a: Vec<_> = (0..population_a.len()).map(|a| fitness_a(a, false)).collect();
+
+        reevaluate(&self.ga_a.invalid_fitness_policy, &mut fitnesses_a, |a| fitness_a(a, true));
+
+        let mut fitness_b = |b: usize, retry: bool| average(opponents_of_b[b].iter().map(|&a| score(a, b, retry).1));
+        let mut fitnesses_b: Vec<_> = (0..population_b.len()).map(|b| fitness_b(b, false)).collect();
+
+        reevaluate(&self.ga_b.invalid_fitness_policy, &mut fitnesses_b, |b| fitness_b(b, true));
+
+        let mut evaluator_a = |candidates: &[&A]| -> Vec<Option<f32>> {
+            candidates
+                .iter()
+                .map(|a| average(candidate_opponents_a.iter().map(|&b| interact(a, &population_b[b]).0)))
+                .collect()
+        };
+
+        let evolved_a = self.ga_a.evolve_scored(rng, population_a, generation, fitnesses_a, &mut evaluator_a)?;
+
+        let mut evaluator_b = |candidates: &[&B]| -> Vec<Option<f32>> {
+            candidates
+                .iter()
+                .map(|b| average(candidate_opponents_b.iter().map(|&a| interact(&population_a[a], b).1)))
+                .collect()
+        };
+
+        let evolved_b = self.ga_b.evolve_scored(rng, population_b, generation, fitnesses_b, &mut evaluator_b)?;
+
+        Ok((evolved_a, evolved_b))
+    }
+
+    fn candidate_opponents(&self, rng: &mut dyn RngCore, opponents_of: &[Vec<usize>], opponents: usize) -> Vec<usize> {
+        match self.sampling {
+            OpponentSampling::Random(_) => self.sample(rng, 1, opponents).swap_remove(0),
+            OpponentSampling::All | OpponentSampling::Panel(_) => opponents_of[0].clone(),
+        }
+    }
+
+    fn sample(&self, rng: &mut dyn RngCore, size: usize, opponents: usize) -> Vec<Vec<usize>> {
+        match self.sampling {
+            OpponentSampling::All => vec![(0..opponents).collect(); size],
+
+            OpponentSampling::Random(count) => (0..size)
+                .map(|_| index::sample(rng, opponents, count.min(opponents)).into_vec())
+                .collect(),
+
+            OpponentSampling::Panel(count) => {
+                vec![index::sample(rng, opponents, count.min(opponents)).into_vec(); size]
+            }
+        }
+    }
+}
+
+fn average(scores: impl Iterator<Item = f32>) -> Option<f32> {
+    let (sum, count) = scores.fold((0.0, 0), |(sum, count), score| (sum + score, count + 1));
+
+    (count > 0).then(|| sum / count as f32).filter(|fitness| fitness.is_finite())
+}
+
+/// Coevolution's counterpart to the retries `evaluation::fitnesses` does
+/// under `InvalidFitnessPolicy::Reevaluate`.
+fn reevaluate(policy: &InvalidFitnessPolicy, fitnesses: &mut [Option<f32>], mut evaluate: impl FnMut(usize) -> Option<f32>) {
+    if let InvalidFitnessPolicy::Reevaluate(retries) = policy {
+        for _ in 0..*retries {
+            let mut failed = false;
+
+            for (idx, fitness) in fitnesses.iter_mut().enumerate().filter(|(_, fitness)| fitness.is_none()) {
+                *fitness = evaluate(idx);
+                failed = true;
+            }
+
+            if !failed {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::HashSet;
+
+    fn coevolution(sampling: OpponentSampling) -> Coevolution<RouletteWheelSelection, RouletteWheelSelection> {
+        Coevolution::new(
+            GeneticAlgorithm::new(RouletteWheelSelection, UniformCrossover, GaussianMutation::new(0.5, 0.5)),
+            GeneticAlgorithm::new(RouletteWheelSelection, UniformCrossover, GaussianMutation::new(0.5, 0.5)),
+            sampling,
+        )
+    }
+
+    fn population(rng: &mut dyn RngCore, size: usize) -> Vec<TestIndividual> {
+        (0..size)
+            .map(|_| TestIndividual::create(vec![rng.gen_range(0.0..=1.0)].into_iter().collect()))
+            .collect()
+    }
+
+    /// Returns how many interactions were played and how many distinct pairs
+    /// the two sides sampled, replaying the draws from the same rng state.
+    fn interactions(sampling: OpponentSampling) -> (usize, usize) {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population_a = population(&mut rng, 6);
+        let population_b = population(&mut rng, 4);
+        let coevolution = coevolution(sampling);
+        let mut interactions = 0;
+
+        let mut replay = rng.clone();
+        let opponents_of_a = coevolution.sample(&mut replay, 6, 4);
+        let opponents_of_b = coevolution.sample(&mut replay, 4, 6);
+
+        let pairs: HashSet<_> = opponents_of_a
+            .iter()
+            .enumerate()
+            .flat_map(|(a, opponents)| opponents.iter().map(move |&b| (a, b)))
+            .chain(opponents_of_b.iter().enumerate().flat_map(|(b, opponents)| opponents.iter().map(move |&a| (a, b))))
+            .collect();
+
+        coevolution.evolve(&mut rng, &population_a, &population_b, 0, &mut |_, _| {
+            interactions += 1;
+            (1.0, 1.0)
+        });
+
+        (interactions, pairs.len())
+    }
+
+    #[test]
+    fn every_pair_interacts_at_most_once() {
+        assert_eq!(interactions(OpponentSampling::All), (6 * 4, 6 * 4));
+        // Both panels' 2 x 2 shared pairs are only played once
+        assert_eq!(interactions(OpponentSampling::Panel(2)), (2 * 6 + 2 * 4 - 2 * 2, 2 * 6 + 2 * 4 - 2 * 2));
+
+        let (played, sampled) = interactions(OpponentSampling::Random(2));
+
+        assert_eq!(played, sampled);
+        assert!(sampled <= 2 * 6 + 2 * 4);
+    }
+
+    #[test]
+    fn candidates_meet_sampled_opponents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population_a = population(&mut rng, 6);
+        let population_b = population(&mut rng, 4);
+        let mut opponents = Vec::new();
+
+        let coevolution = Coevolution::new(
+            GeneticAlgorithm::new(RouletteWheelSelection, UniformCrossover, GaussianMutation::new(0.5, 0.5))
+                .with_local_search(HillClimbing::new(3, 0.1), Inheritance::Baldwinian),
+            GeneticAlgorithm::new(RouletteWheelSelection, UniformCrossover, GaussianMutation::new(0.5, 0.5)),
+            OpponentSampling::Random(2),
+        );
+
+        coevolution.evolve(&mut rng, &population_a, &population_b, 0, &mut |a, b| {
+            if population_a.iter().all(|incumbent| incumbent.chromosome()[0] != a.chromosome()[0]) {
+                opponents.push(b.chromosome()[0]);
+            }
+
+            (1.0, 1.0)
+        });
+
+        opponents.sort_by(f32::total_cmp);
+        opponents.dedup();
+
+        assert!(!opponents.is_empty());
+        assert!(opponents.len() <= 2);
+    }
+
+    #[test]
+    fn reevaluates_failed_interactions() {
+        let evolve = |policy: InvalidFitnessPolicy| {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let population_a = population(&mut rng, 3);
+            let population_b = population(&mut rng, 2);
+            let mut failures = 1;
+
+            let coevolution = Coevolution::new(
+                GeneticAlgorithm::new(RouletteWheelSelection, UniformCrossover, GaussianMutation::new(0.5, 0.5))
+                    .with_invalid_fitness_policy(policy.clone()),
+                GeneticAlgorithm::new(RouletteWheelSelection, UniformCrossover, GaussianMutation::new(0.5, 0.5))
+                    .with_invalid_fitness_policy(policy),
+                OpponentSampling::All,
+            );
+
+            // The very first encounter fails for both sides
+            let ((_, stats_a), (_, stats_b)) = coevolution.evolve(&mut rng, &population_a, &population_b, 0, &mut |_, _| {
+                if failures > 0 {
+                    failures -= 1;
+                    (f32::NAN, f32::NAN)
+                } else {
+                    (1.0, 1.0)
+                }
+            });
+
+            (stats_a.invalid_count, stats_b.invalid_count)
+        };
+
+        assert_eq!(evolve(InvalidFitnessPolicy::AssignWorst), (1, 1));
+        assert_eq!(evolve(InvalidFitnessPolicy::Reevaluate(1)), (0, 0));
+    }
+
+    #[test]
+    fn predators_and_prey_race_each_other() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut predators = population(&mut rng, 20);
+        let mut prey = population(&mut rng, 20);
+
+        // Whoever is faster wins the encounter
+        let mut chase = |predator: &TestIndividual, prey: &TestIndividual| {
+            if predator.fitness() > prey.fitness() {
+                (1.0, 0.1)
+            } else {
+                (0.1, 1.0)
+            }
+        };
+
+        let speed = |population: &[TestIndividual]| {
+            population.iter().map(|individual| individual.fitness()).sum::<f32>() / population.len() as f32
+        };
+
+        let (predators_before, prey_before) = (speed(&predators), speed(&prey));
+
+        for generation in 0..30 {
+            ((predators, _), (prey, _)) = coevolution(OpponentSampling::Random(5))
+                .evolve(&mut rng, &predators, &prey, generation, &mut chase);
+        }
+
+        assert!(speed(&predators) > predators_before + 1.0);
+        assert!(speed(&prey) > prey_before + 1.0);
+    }
+}
@@ -4,5 +4,9 @@ pub use self::uniform::*;
 use crate::*;
 
 pub trait CrossoverMethod {
-    fn crossover(&self, rng: &mut dyn RngCore, parent_a: &Chromosome, parent_b: &Chromosome) -> Chromosome;
-}
\ No newline at end of file
+    fn crossover(&self, rng: &mut dyn RngCore, parent_a: &Chromosome, parent_b: &Chromosome) -> Chromosome {
+        self.try_crossover(rng, parent_a, parent_b).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_crossover(&self, rng: &mut dyn RngCore, parent_a: &Chromosome, parent_b: &Chromosome) -> Result<Chromosome, GaError>;
+}
@@ -1,17 +1,20 @@
 use crate::*;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniformCrossover;
 
 impl CrossoverMethod for UniformCrossover {
-    fn crossover(&self, rng: &mut dyn RngCore, parent_a: &Chromosome, parent_b: &Chromosome) -> Chromosome {
-        assert_eq!(parent_a.len(), parent_b.len());
+    fn try_crossover(&self, rng: &mut dyn RngCore, parent_a: &Chromosome, parent_b: &Chromosome) -> Result<Chromosome, GaError> {
+        if parent_a.len() != parent_b.len() {
+            return Err(GaError::LengthMismatch { expected: parent_a.len(), actual: parent_b.len() });
+        }
 
-        parent_a
+        Ok(parent_a
             .iter()
             .zip(parent_b.iter())
             .map(|(&a, &b)| if rng.gen_bool(0.5) { a } else { b })
-            .collect()
+            .collect())
     }
 }
 
@@ -35,4 +38,16 @@ mod tests {
         assert_eq!(diff_b, 51);
     
     }
+
+    #[test]
+    fn uniform_crossover_with_mismatched_parents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a = (1..=3).map(|n| n as f32).collect();
+        let parent_b = (1..=4).map(|n| n as f32).collect();
+
+        assert_eq!(
+            UniformCrossover.try_crossover(&mut rng, &parent_a, &parent_b).err(),
+            Some(GaError::LengthMismatch { expected: 3, actual: 4 }),
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GaError {
+    EmptyPopulation,
+    NoValidIndividuals,
+    LengthMismatch { expected: usize, actual: usize },
+    InvalidFitness,
+    ZeroTotalFitness,
+    /// A deserialized value that its constructor would have rejected.
+    InvalidConfig(&'static str),
+}
+
+impl fmt::Display for GaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyPopulation => write!(f, "population is empty"),
+            Self::NoValidIndividuals => write!(f, "population has no valid individuals"),
+            Self::LengthMismatch { expected, actual } => {
+                write!(f, "expected a chromosome with {} genes, got {}", expected, actual)
+            }
+            Self::InvalidFitness => write!(f, "fitness must be finite and non-negative"),
+            Self::ZeroTotalFitness => write!(f, "total fitness of the population is zero"),
+            Self::InvalidConfig(reason) => write!(f, "invalid configuration: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for GaError {}
@@ -0,0 +1,214 @@
+use crate::*;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InvalidFitnessPolicy {
+    Discard,
+    AssignWorst,
+    /// Retries the evaluation up to the given number of times; individuals
+    /// that still fail are discarded.
+    Reevaluate(usize),
+}
+
+impl Default for InvalidFitnessPolicy {
+    fn default() -> Self {
+        Self::AssignWorst
+    }
+}
+
+/// Snapshot of an individual with its fitness already computed, so that
+/// selection and statistics never have to evaluate it again.
+pub(crate) struct Evaluated<'a> {
+    pub(crate) chromosome: &'a Chromosome,
+    pub(crate) fitness: f32,
+}
+
+/// Only ever handed to selection and statistics, which borrow individuals
+/// but never breed them; offspring are created as the caller's `I`.
+impl Individual for Evaluated<'_> {
+    fn create(_: Chromosome) -> Self {
+        unreachable!("evaluated individuals are never created from a chromosome")
+    }
+
+    fn fitness(&self) -> f32 {
+        self.fitness
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        self.chromosome
+    }
+}
+
+pub(crate) fn fitnesses<I>(
+    population: &[I],
+    evaluator: &mut dyn Evaluator<I>,
+    policy: &InvalidFitnessPolicy,
+) -> Result<Vec<Option<f32>>, GaError> {
+    let individuals: Vec<_> = population.iter().collect();
+    let mut fitnesses = evaluate_finite(evaluator, &individuals)?;
+
+    if let InvalidFitnessPolicy::Reevaluate(retries) = policy {
+        for _ in 0..*retries {
+            let failed: Vec<_> = (0..fitnesses.len())
+                .filter(|&idx| fitnesses[idx].is_none())
+                .collect();
+
+            if failed.is_empty() {
+                break;
+            }
+
+            let retried: Vec<_> = failed.iter().map(|&idx| individuals[idx]).collect();
+
+            for (idx, fitness) in failed.into_iter().zip(evaluate_finite(evaluator, &retried)?) {
+                fitnesses[idx] = fitness;
+            }
+        }
+    }
+
+    Ok(fitnesses)
+}
+
+/// Pairs every individual with its fitness, applying `policy` to the ones
+/// that have none.
+pub(crate) fn assemble<'a, I>(
+    population: &'a [I],
+    fitnesses: Vec<Option<f32>>,
+    policy: &InvalidFitnessPolicy,
+) -> Result<(Vec<Evaluated<'a>>, usize), GaError> where I: Individual {
+    assert_eq!(population.len(), fitnesses.len());
+
+    let fitnesses: Vec<_> = fitnesses
+        .into_iter()
+        .map(|fitness| fitness.filter(|fitness| fitness.is_finite()))
+        .collect();
+
+    let invalid = fitnesses.iter().filter(|fitness| fitness.is_none()).count();
+
+    let worst = fitnesses
+        .iter()
+        .flatten()
+        .copied()
+        .reduce(f32::min);
+
+    let evaluated: Vec<_> = population
+        .iter()
+        .zip(fitnesses)
+        .filter_map(|(individual, fitness)| {
+            let fitness = match policy {
+                InvalidFitnessPolicy::AssignWorst => fitness.or(worst),
+                _ => fitness,
+            }?;
+
+            Some(Evaluated { chromosome: individual.chromosome(), fitness })
+        })
+        .collect();
+
+    if evaluated.is_empty() {
+        return Err(GaError::NoValidIndividuals);
+    }
+
+    Ok((evaluated, invalid))
+}
+
+fn evaluate_finite<I>(evaluator: &mut dyn Evaluator<I>, individuals: &[&I]) -> Result<Vec<Option<f32>>, GaError> {
+    let fitnesses = evaluator.try_evaluate(individuals)?;
+
+    Ok(fitnesses
+        .into_iter()
+        .map(|fitness| fitness.filter(|fitness| fitness.is_finite()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct FlakyIndividual {
+        chromosome: Chromosome,
+        failures: Cell<usize>,
+    }
+
+    impl FlakyIndividual {
+        fn new(fitness: f32, failures: usize) -> Self {
+            Self { chromosome: vec![fitness].into_iter().collect(), failures: Cell::new(failures) }
+        }
+    }
+
+    impl Individual for FlakyIndividual {
+        fn create(chromosome: Chromosome) -> Self {
+            Self { chromosome, failures: Cell::new(0) }
+        }
+
+        fn fitness(&self) -> f32 {
+            self.chromosome[0]
+        }
+
+        fn try_fitness(&self) -> Option<f32> {
+            if self.failures.get() > 0 {
+                self.failures.set(self.failures.get() - 1);
+                None
+            } else {
+                Some(self.fitness())
+            }
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            &self.chromosome
+        }
+    }
+
+    fn evaluate<'a>(population: &'a [FlakyIndividual], policy: &InvalidFitnessPolicy) -> (Vec<Evaluated<'a>>, usize) {
+        assemble(population, fitnesses(population, &mut FitnessEvaluator, policy).unwrap(), policy).unwrap()
+    }
+
+    fn fitness_values(evaluated: &[Evaluated]) -> Vec<f32> {
+        evaluated.iter().map(|individual| individual.fitness()).collect()
+    }
+
+    fn population() -> Vec<FlakyIndividual> {
+        vec![
+            FlakyIndividual::new(2.0, 0),
+            FlakyIndividual::new(f32::NAN, 0),
+            FlakyIndividual::new(3.0, 1),
+            FlakyIndividual::new(f32::INFINITY, 0),
+            FlakyIndividual::new(5.0, 0),
+        ]
+    }
+
+    #[test]
+    fn discard() {
+        let population = population();
+        let (evaluated, invalid) = evaluate(&population, &InvalidFitnessPolicy::Discard);
+
+        assert_eq!(fitness_values(&evaluated), vec![2.0, 5.0]);
+        assert_eq!(invalid, 3);
+    }
+
+    #[test]
+    fn assign_worst() {
+        let population = population();
+        let (evaluated, invalid) = evaluate(&population, &InvalidFitnessPolicy::AssignWorst);
+
+        assert_eq!(fitness_values(&evaluated), vec![2.0, 2.0, 2.0, 2.0, 5.0]);
+        assert_eq!(invalid, 3);
+    }
+
+    #[test]
+    fn reevaluate() {
+        let population = population();
+        let (evaluated, invalid) = evaluate(&population, &InvalidFitnessPolicy::Reevaluate(1));
+
+        assert_eq!(fitness_values(&evaluated), vec![2.0, 3.0, 5.0]);
+        assert_eq!(invalid, 2);
+    }
+
+    #[test]
+    fn all_invalid() {
+        let population = vec![FlakyIndividual::new(f32::NAN, 0)];
+        let policy = InvalidFitnessPolicy::AssignWorst;
+        let fitnesses = fitnesses(&population, &mut FitnessEvaluator, &policy).unwrap();
+
+        assert_eq!(assemble(&population, fitnesses, &policy).err(), Some(GaError::NoValidIndividuals));
+    }
+}
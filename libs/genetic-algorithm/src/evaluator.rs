@@ -0,0 +1,40 @@
+mod cached;
+
+pub use self::cached::*;
+use crate::*;
+
+/// Computes the fitness of many individuals at once, returning `None` for
+/// the ones that could not be evaluated.
+pub trait Evaluator<I> {
+    fn evaluate(&mut self, individuals: &[&I]) -> Vec<Option<f32>>;
+
+    /// Like `evaluate`, but rejects a result that doesn't hold exactly one
+    /// fitness per individual.
+    fn try_evaluate(&mut self, individuals: &[&I]) -> Result<Vec<Option<f32>>, GaError> {
+        let fitnesses = self.evaluate(individuals);
+
+        if fitnesses.len() != individuals.len() {
+            return Err(GaError::LengthMismatch { expected: individuals.len(), actual: fitnesses.len() });
+        }
+
+        Ok(fitnesses)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FitnessEvaluator;
+
+impl<I> Evaluator<I> for FitnessEvaluator where I: Individual {
+    fn evaluate(&mut self, individuals: &[&I]) -> Vec<Option<f32>> {
+        individuals
+            .iter()
+            .map(|individual| individual.try_fitness())
+            .collect()
+    }
+}
+
+impl<I, F> Evaluator<I> for F where F: FnMut(&[&I]) -> Vec<Option<f32>> {
+    fn evaluate(&mut self, individuals: &[&I]) -> Vec<Option<f32>> {
+        self(individuals)
+    }
+}
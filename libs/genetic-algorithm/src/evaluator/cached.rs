@@ -0,0 +1,141 @@
+use crate::*;
+use std::collections::HashMap;
+
+/// Remembers the fitness of every chromosome it has seen, so that only
+/// new chromosomes reach the wrapped evaluator.
+///
+/// This is only correct when fitness is a pure function of the chromosome;
+/// failed evaluations are not remembered.
+#[derive(Clone, Debug)]
+pub struct CachedEvaluator<E> {
+    evaluator: E,
+    cache: HashMap<Vec<u32>, f32>,
+}
+
+impl<E> CachedEvaluator<E> {
+    pub fn new(evaluator: E) -> Self {
+        Self { evaluator, cache: HashMap::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    fn key(chromosome: &Chromosome) -> Vec<u32> {
+        chromosome.iter().map(|gene| gene.to_bits()).collect()
+    }
+}
+
+impl<I, E> Evaluator<I> for CachedEvaluator<E> where I: Individual, E: Evaluator<I> {
+    fn evaluate(&mut self, individuals: &[&I]) -> Vec<Option<f32>> {
+        self.try_evaluate(individuals).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_evaluate(&mut self, individuals: &[&I]) -> Result<Vec<Option<f32>>, GaError> {
+        let keys: Vec<_> = individuals
+            .iter()
+            .map(|individual| Self::key(individual.chromosome()))
+            .collect();
+
+        let mut fitnesses: Vec<_> = keys
+            .iter()
+            .map(|key| self.cache.get(key).copied())
+            .collect();
+
+        let misses: Vec<_> = (0..individuals.len())
+            .filter(|&idx| fitnesses[idx].is_none())
+            .collect();
+
+        if misses.is_empty() {
+            return Ok(fitnesses);
+        }
+
+        let missing: Vec<_> = misses.iter().map(|&idx| individuals[idx]).collect();
+        let evaluated = self.evaluator.try_evaluate(&missing)?;
+
+        for (idx, fitness) in misses.into_iter().zip(evaluated) {
+            if let Some(fitness) = fitness.filter(|fitness| fitness.is_finite()) {
+                self.cache.insert(keys[idx].clone(), fitness);
+            }
+
+            fitnesses[idx] = fitness;
+        }
+
+        Ok(fitnesses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    #[test]
+    fn evaluates_each_chromosome_once() {
+        let mut calls = 0;
+
+        let mut evaluator = CachedEvaluator::new(|individuals: &[&TestIndividual]| {
+            calls += individuals.len();
+
+            individuals
+                .iter()
+                .map(|individual| Some(individual.fitness()))
+                .collect()
+        });
+
+        let a = individual(&[1.0, 2.0]);
+        let b = individual(&[3.0, 4.0]);
+        let c = individual(&[1.0, 2.0]);
+
+        assert_eq!(evaluator.evaluate(&[&a, &b, &c]), vec![Some(3.0), Some(7.0), Some(3.0)]);
+        assert_eq!(evaluator.evaluate(&[&b, &a]), vec![Some(7.0), Some(3.0)]);
+        assert_eq!(evaluator.len(), 2);
+
+        drop(evaluator);
+
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn does_not_remember_failures() {
+        let mut calls = 0;
+
+        let mut evaluator = CachedEvaluator::new(|individuals: &[&TestIndividual]| {
+            calls += individuals.len();
+
+            vec![None; individuals.len()]
+        });
+
+        let a = individual(&[1.0, 2.0]);
+
+        assert_eq!(evaluator.evaluate(&[&a]), vec![None]);
+        assert_eq!(evaluator.evaluate(&[&a]), vec![None]);
+        assert!(evaluator.is_empty());
+
+        drop(evaluator);
+
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn rejects_the_wrong_number_of_fitnesses() {
+        let mut evaluator = CachedEvaluator::new(|_: &[&TestIndividual]| vec![Some(1.0)]);
+
+        let a = individual(&[1.0, 2.0]);
+        let b = individual(&[3.0, 4.0]);
+
+        assert_eq!(evaluator.try_evaluate(&[&a, &b]), Err(GaError::LengthMismatch { expected: 2, actual: 1 }));
+        assert!(evaluator.is_empty());
+    }
+}
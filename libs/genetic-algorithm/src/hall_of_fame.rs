@@ -0,0 +1,228 @@
+use crate::*;
+use std::mem;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawHallOfFame"))]
+pub struct HallOfFame {
+    capacity: usize,
+    similarity: f32,
+    entries: Vec<HallOfFameEntry>,
+}
+
+/// Deserialized form of a `HallOfFame`, checked against what `new` asserts.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawHallOfFame {
+    capacity: usize,
+    similarity: f32,
+    entries: Vec<HallOfFameEntry>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawHallOfFame> for HallOfFame {
+    type Error = GaError;
+
+    fn try_from(raw: RawHallOfFame) -> Result<Self, Self::Error> {
+        if raw.capacity == 0 {
+            return Err(GaError::InvalidConfig("hall of fame capacity must be positive"));
+        }
+
+        if raw.similarity.is_nan() || raw.similarity < 0.0 {
+            return Err(GaError::InvalidConfig("hall of fame similarity must be non-negative"));
+        }
+
+        if raw.entries.len() > raw.capacity {
+            return Err(GaError::InvalidConfig("hall of fame holds more entries than its capacity"));
+        }
+
+        if raw.entries.iter().any(|entry| !entry.fitness.is_finite()) {
+            return Err(GaError::InvalidConfig("hall of fame entries must have a finite fitness"));
+        }
+
+        if raw.entries.windows(2).any(|pair| pair[0].fitness < pair[1].fitness) {
+            return Err(GaError::InvalidConfig("hall of fame entries must be sorted by descending fitness"));
+        }
+
+        Ok(Self { capacity: raw.capacity, similarity: raw.similarity, entries: raw.entries })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HallOfFameEntry {
+    pub chromosome: Chromosome,
+    pub fitness: f32,
+    pub generation: usize,
+}
+
+impl HallOfFame {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0);
+
+        Self { capacity, similarity: 0.0, entries: Vec::new() }
+    }
+
+    /// Chromosomes closer to each other than `similarity` (by euclidean
+    /// distance) count as the same individual; only the fitter one is kept.
+    pub fn with_similarity(mut self, similarity: f32) -> Self {
+        assert!(similarity >= 0.0);
+
+        self.similarity = similarity;
+        self
+    }
+
+    pub fn update<I>(&mut self, population: &[I], generation: usize) where I: Individual {
+        for individual in population {
+            let Some(fitness) = individual.try_fitness().filter(|fitness| fitness.is_finite()) else {
+                continue;
+            };
+
+            self.insert(HallOfFameEntry {
+                chromosome: individual.chromosome().clone(),
+                fitness,
+                generation,
+            });
+        }
+    }
+
+    pub fn entries(&self) -> &[HallOfFameEntry] {
+        &self.entries
+    }
+
+    pub fn best(&self) -> Option<&HallOfFameEntry> {
+        self.entries.first()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn insert(&mut self, entry: HallOfFameEntry) {
+        if self
+            .entries
+            .iter()
+            .any(|other| other.fitness >= entry.fitness && self.is_similar(&entry.chromosome, &other.chromosome))
+        {
+            return;
+        }
+
+        let entries = mem::take(&mut self.entries);
+        self.entries = entries.into_iter().filter(|other| !self.is_similar(&entry.chromosome, &other.chromosome)).collect();
+
+        if self.entries.len() == self.capacity {
+            if self.entries[self.capacity - 1].fitness >= entry.fitness {
+                return;
+            }
+
+            self.entries.pop();
+        }
+
+        let idx = self.entries.partition_point(|other| other.fitness >= entry.fitness);
+        self.entries.insert(idx, entry);
+    }
+
+    fn is_similar(&self, a: &Chromosome, b: &Chromosome) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+
+        let distance = a
+            .iter()
+            .zip(b.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f32>()
+            .sqrt();
+
+        distance <= self.similarity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    fn fitnesses(hall_of_fame: &HallOfFame) -> Vec<(f32, usize)> {
+        hall_of_fame
+            .entries()
+            .iter()
+            .map(|entry| (entry.fitness, entry.generation))
+            .collect()
+    }
+
+    #[test]
+    fn keeps_the_best_individuals_across_generations() {
+        let mut hall_of_fame = HallOfFame::new(3);
+
+        hall_of_fame.update(&[individual(&[1.0, 1.0]), individual(&[5.0, 0.0]), individual(&[0.0, 0.0])], 0);
+        hall_of_fame.update(&[individual(&[2.0, 2.0]), individual(&[0.5, 0.0])], 1);
+        hall_of_fame.update(&[individual(&[3.0, 3.0]), individual(&[f32::NAN, 9.0])], 2);
+
+        assert_eq!(fitnesses(&hall_of_fame), vec![(6.0, 2), (5.0, 0), (4.0, 1)]);
+        assert_eq!(hall_of_fame.best().unwrap().chromosome, individual(&[3.0, 3.0]).chromosome().clone());
+    }
+
+    #[test]
+    fn skips_duplicates() {
+        let mut hall_of_fame = HallOfFame::new(3);
+
+        hall_of_fame.update(&[individual(&[1.0, 1.0]), individual(&[1.0, 1.0])], 0);
+        hall_of_fame.update(&[individual(&[1.0, 1.0])], 1);
+
+        assert_eq!(fitnesses(&hall_of_fame), vec![(2.0, 0)]);
+    }
+
+    #[test]
+    fn replaces_similar_individuals_with_fitter_ones() {
+        let mut hall_of_fame = HallOfFame::new(3).with_similarity(0.5);
+
+        hall_of_fame.update(&[individual(&[1.0, 1.0]), individual(&[3.0, 3.0])], 0);
+        hall_of_fame.update(&[individual(&[1.0, 1.25]), individual(&[2.75, 3.0])], 1);
+
+        assert_eq!(fitnesses(&hall_of_fame), vec![(6.0, 0), (2.25, 1)]);
+    }
+
+    #[test]
+    fn replaces_every_similar_individual() {
+        let mut hall_of_fame = HallOfFame::new(3).with_similarity(0.5);
+
+        hall_of_fame.update(&[individual(&[0.0, 0.0]), individual(&[0.625, 0.0])], 0);
+        hall_of_fame.update(&[individual(&[0.3125, 0.375])], 1);
+
+        assert_eq!(fitnesses(&hall_of_fame), vec![(0.6875, 1)]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates() {
+        let hall_of_fame: HallOfFame = serde_json::from_str(r#"{"capacity":2,"similarity":0.0,"entries":[]}"#).unwrap();
+
+        assert!(hall_of_fame.is_empty());
+
+        for json in [
+            r#"{"capacity":0,"similarity":0.0,"entries":[]}"#,
+            r#"{"capacity":1,"similarity":-1.0,"entries":[]}"#,
+            r#"{"capacity":1,"similarity":0.0,"entries":[
+                {"chromosome":[1.0],"fitness":1.0,"generation":0},
+                {"chromosome":[2.0],"fitness":2.0,"generation":0}
+            ]}"#,
+            r#"{"capacity":2,"similarity":0.0,"entries":[
+                {"chromosome":[1.0],"fitness":1.0,"generation":0},
+                {"chromosome":[2.0],"fitness":2.0,"generation":0}
+            ]}"#,
+            r#"{"capacity":1,"similarity":0.0,"entries":[
+                {"chromosome":[1.0],"fitness":1e39,"generation":0}
+            ]}"#,
+        ] {
+            assert!(serde_json::from_str::<HallOfFame>(json).is_err(), "{}", json);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::*;
+
+/// Anti-stagnation policy: every `interval` generations, `count` offspring
+/// are replaced with fresh individuals drawn from `method`; see
+/// `GeneticAlgorithm::with_immigration`.
+pub struct RandomImmigrants {
+    method: Box<dyn InitializationMethod>,
+    count: usize,
+    interval: usize,
+}
+
+impl RandomImmigrants {
+    pub fn new(method: impl InitializationMethod + 'static, count: usize, interval: usize) -> Self {
+        assert!(interval > 0);
+
+        Self { method: Box::new(method), count, interval }
+    }
+
+    pub(crate) fn immigrate<I>(&self, rng: &mut dyn RngCore, population: &mut Vec<I>, generation: usize) where I: Individual {
+        if (generation + 1) % self.interval != 0 {
+            return;
+        }
+
+        let count = self.count.min(population.len());
+
+        population.truncate(population.len() - count);
+        population.extend(self.method.initialize(rng, count).into_iter().map(I::create));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    fn immigrants(population: &[TestIndividual]) -> usize {
+        population
+            .iter()
+            .filter(|individual| individual.chromosome()[0] >= 10.0)
+            .count()
+    }
+
+    #[test]
+    fn random_immigrants() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let immigration = RandomImmigrants::new(
+            UniformInitialization::new(Bounds::uniform(10.0, 20.0), 2),
+            2,
+            3,
+        );
+
+        let population = vec![individual(&[0.0, 0.0]), individual(&[1.0, 1.0]), individual(&[2.0, 2.0])];
+
+        for generation in 0..6 {
+            let mut population = population.clone();
+            immigration.immigrate(&mut rng, &mut population, generation);
+
+            let expected = if generation % 3 == 2 { 2 } else { 0 };
+
+            assert_eq!(population.len(), 3);
+            assert_eq!(immigrants(&population), expected);
+        }
+    }
+}
@@ -4,6 +4,16 @@ pub trait Individual {
     fn create(chromosome: Chromosome) -> Self;
     fn fitness(&self) -> f32;
     fn chromosome(&self) -> &Chromosome;
+
+    /// Returns `None` when the individual could not be evaluated; NaN and
+    /// infinite fitness are treated as failures as well.
+    fn try_fitness(&self) -> Option<f32> {
+        Some(self.fitness())
+    }
+
+    /// Receives the fitness local search reached from this offspring under
+    /// `Inheritance::Baldwinian`; ignored unless the individual stores it.
+    fn set_learned_fitness(&mut self, _fitness: f32) {}
 }
 
 #[cfg(test)]
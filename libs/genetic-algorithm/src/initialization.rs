@@ -0,0 +1,21 @@
+mod latin_hypercube;
+mod opposition;
+mod seeded;
+mod uniform;
+
+pub use self::latin_hypercube::*;
+pub use self::opposition::*;
+pub use self::seeded::*;
+pub use self::uniform::*;
+use crate::*;
+
+pub trait InitializationMethod {
+    fn initialize(&self, rng: &mut dyn RngCore, size: usize) -> Vec<Chromosome>;
+
+    fn population<I>(&self, rng: &mut dyn RngCore, size: usize) -> Vec<I> where I: Individual, Self: Sized {
+        self.initialize(rng, size)
+            .into_iter()
+            .map(I::create)
+            .collect()
+    }
+}
@@ -0,0 +1,95 @@
+use crate::*;
+
+/// Splits every gene's range into as many equal strata as there are
+/// individuals and puts exactly one individual into each stratum, which
+/// spreads the initial population more evenly than uniform sampling.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawLatinHypercubeInitialization"))]
+pub struct LatinHypercubeInitialization {
+    bounds: Bounds,
+    genes: usize,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawLatinHypercubeInitialization {
+    bounds: Bounds,
+    genes: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawLatinHypercubeInitialization> for LatinHypercubeInitialization {
+    type Error = GaError;
+
+    fn try_from(raw: RawLatinHypercubeInitialization) -> Result<Self, Self::Error> {
+        raw.bounds.try_check_len(raw.genes)?;
+
+        Ok(Self { bounds: raw.bounds, genes: raw.genes })
+    }
+}
+
+impl LatinHypercubeInitialization {
+    pub fn new(bounds: Bounds, genes: usize) -> Self {
+        bounds.try_check_len(genes).unwrap_or_else(|err| panic!("{}", err));
+
+        Self { bounds, genes }
+    }
+}
+
+impl InitializationMethod for LatinHypercubeInitialization {
+    fn initialize(&self, rng: &mut dyn RngCore, size: usize) -> Vec<Chromosome> {
+        let mut genes = vec![Vec::with_capacity(self.genes); size];
+
+        for gene in 0..self.genes {
+            let (lower, upper) = self.bounds.range(gene);
+
+            let mut strata: Vec<_> = (0..size).collect();
+            strata.shuffle(rng);
+
+            for (chromosome, stratum) in genes.iter_mut().zip(strata) {
+                let offset = (stratum as f32 + rng.gen_range(0.0..1.0)) / size as f32;
+
+                chromosome.push(lower + offset * (upper - lower));
+            }
+        }
+
+        genes.into_iter().map(|genes| genes.into_iter().collect()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn every_stratum_is_used_once() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let bounds = Bounds::per_gene([(0.0, 1.0), (-10.0, 10.0)]);
+
+        let population = LatinHypercubeInitialization::new(bounds.clone(), 2).initialize(&mut rng, 10);
+
+        assert!(population.iter().all(|chromosome| bounds.contains(chromosome)));
+
+        for gene in 0..2 {
+            let (lower, upper) = bounds.range(gene);
+
+            let mut strata: Vec<_> = population
+                .iter()
+                .map(|chromosome| ((chromosome[gene] - lower) / (upper - lower) * 10.0) as usize)
+                .collect();
+
+            strata.sort();
+
+            assert_eq!(strata, (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a chromosome with 2 genes, got 1")]
+    fn mismatched_bounds() {
+        LatinHypercubeInitialization::new(Bounds::per_gene([(0.0, 1.0), (0.0, 1.0)]), 1);
+    }
+}
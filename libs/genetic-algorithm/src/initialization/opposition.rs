@@ -0,0 +1,154 @@
+use crate::*;
+
+/// Opposition-based initialization: every chromosome drawn by the wrapped
+/// method is accompanied by its opposite, mirrored through the middle of the
+/// bounds.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OppositionInitialization<M> {
+    method: M,
+    bounds: Bounds,
+}
+
+impl<M> OppositionInitialization<M> where M: InitializationMethod {
+    pub fn new(method: M, bounds: Bounds) -> Self {
+        Self { method, bounds }
+    }
+
+    /// Classic opposition-based learning: draws `size` chromosomes together
+    /// with their opposites and keeps the `size` fittest of them.
+    pub fn fittest<I>(&self, rng: &mut dyn RngCore, size: usize, evaluator: &mut dyn Evaluator<I>) -> Vec<I> where I: Individual {
+        self.try_fittest(rng, size, evaluator).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_fittest<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        size: usize,
+        evaluator: &mut dyn Evaluator<I>,
+    ) -> Result<Vec<I>, GaError> where I: Individual {
+        let candidates: Vec<I> = self
+            .method
+            .initialize(rng, size)
+            .into_iter()
+            .map(|chromosome| {
+                let opposite = self.try_opposite(&chromosome)?;
+                Ok([chromosome, opposite])
+            })
+            .collect::<Result<Vec<_>, GaError>>()?
+            .into_iter()
+            .flatten()
+            .map(I::create)
+            .collect();
+
+        let fitnesses = evaluator.evaluate(&candidates.iter().collect::<Vec<_>>());
+
+        if fitnesses.len() != candidates.len() {
+            return Err(GaError::LengthMismatch { expected: candidates.len(), actual: fitnesses.len() });
+        }
+
+        let mut candidates: Vec<_> = candidates
+            .into_iter()
+            .zip(fitnesses)
+            .map(|(candidate, fitness)| (candidate, fitness.filter(|fitness| fitness.is_finite())))
+            .collect();
+
+        candidates.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap());
+        candidates.truncate(size);
+
+        Ok(candidates.into_iter().map(|(candidate, _)| candidate).collect())
+    }
+
+    fn opposite(&self, chromosome: &Chromosome) -> Chromosome {
+        self.try_opposite(chromosome).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_opposite(&self, chromosome: &Chromosome) -> Result<Chromosome, GaError> {
+        self.bounds.try_check_len(chromosome.len())?;
+
+        Ok(chromosome
+            .iter()
+            .enumerate()
+            .map(|(gene, value)| {
+                let (lower, upper) = self.bounds.range(gene);
+
+                lower + upper - value
+            })
+            .collect())
+    }
+}
+
+impl<M> InitializationMethod for OppositionInitialization<M> where M: InitializationMethod {
+    fn initialize(&self, rng: &mut dyn RngCore, size: usize) -> Vec<Chromosome> {
+        self.method
+            .initialize(rng, size.div_ceil(2))
+            .into_iter()
+            .flat_map(|chromosome| {
+                let opposite = self.opposite(&chromosome);
+                [chromosome, opposite]
+            })
+            .take(size)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn opposition() -> OppositionInitialization<UniformInitialization> {
+        let bounds = Bounds::uniform(-1.0, 3.0);
+
+        OppositionInitialization::new(UniformInitialization::new(bounds.clone(), 4), bounds)
+    }
+
+    #[test]
+    fn pairs_chromosomes_with_their_opposites() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = opposition().initialize(&mut rng, 5);
+
+        assert_eq!(population.len(), 5);
+
+        for pair in population.chunks(2).filter(|pair| pair.len() == 2) {
+            for gene in 0..4 {
+                assert_relative_eq!(pair[0][gene] + pair[1][gene], 2.0);
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_the_fittest_candidates() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population: Vec<TestIndividual> = opposition().fittest(&mut rng, 5, &mut FitnessEvaluator);
+
+        assert_eq!(population.len(), 5);
+
+        // Out of every chromosome and its opposite (whose genes sum up to 8)
+        // the fitter one is kept
+        assert!(population.iter().all(|individual| individual.fitness() >= 4.0));
+    }
+
+    #[test]
+    fn fittest_errors() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mismatched = OppositionInitialization::new(
+            UniformInitialization::new(Bounds::uniform(-1.0, 3.0), 4),
+            Bounds::per_gene([(-1.0, 3.0); 3]),
+        );
+
+        assert_eq!(
+            mismatched.try_fittest::<TestIndividual>(&mut rng, 2, &mut FitnessEvaluator).err(),
+            Some(GaError::LengthMismatch { expected: 3, actual: 4 }),
+        );
+
+        let mut short = |_: &[&TestIndividual]| vec![Some(1.0)];
+
+        assert_eq!(
+            opposition().try_fittest::<TestIndividual>(&mut rng, 2, &mut short).err(),
+            Some(GaError::LengthMismatch { expected: 4, actual: 1 }),
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::*;
+
+/// Starts from previously saved chromosomes: the seeds themselves come
+/// first, followed by copies of them perturbed by up to `perturbation` per
+/// gene.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawSeededInitialization"))]
+pub struct SeededInitialization {
+    seeds: Vec<Chromosome>,
+    perturbation: f32,
+    bounds: Option<Bounds>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawSeededInitialization {
+    seeds: Vec<Chromosome>,
+    perturbation: f32,
+    #[serde(default)]
+    bounds: Option<Bounds>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawSeededInitialization> for SeededInitialization {
+    type Error = GaError;
+
+    fn try_from(raw: RawSeededInitialization) -> Result<Self, Self::Error> {
+        let initialization = Self { seeds: raw.seeds, perturbation: raw.perturbation, bounds: raw.bounds };
+
+        initialization.validate()?;
+
+        Ok(initialization)
+    }
+}
+
+impl SeededInitialization {
+    pub fn new(seeds: impl IntoIterator<Item = Chromosome>, perturbation: f32) -> Self {
+        let initialization = Self { seeds: seeds.into_iter().collect(), perturbation, bounds: None };
+
+        initialization.validate().unwrap_or_else(|err| panic!("{}", err));
+        initialization
+    }
+
+    /// Clamps the perturbed copies back into `bounds`.
+    pub fn with_bounds(mut self, bounds: Bounds) -> Self {
+        self.bounds = Some(bounds);
+        self.validate().unwrap_or_else(|err| panic!("{}", err));
+        self
+    }
+
+    fn validate(&self) -> Result<(), GaError> {
+        if self.seeds.is_empty() {
+            return Err(GaError::InvalidConfig("seeded initialization needs at least one seed"));
+        }
+
+        if self.perturbation.is_nan() || self.perturbation < 0.0 {
+            return Err(GaError::InvalidConfig("perturbation must be non-negative"));
+        }
+
+        if let Some(bounds) = &self.bounds {
+            self.seeds.iter().try_for_each(|seed| bounds.try_check(seed))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl InitializationMethod for SeededInitialization {
+    fn initialize(&self, rng: &mut dyn RngCore, size: usize) -> Vec<Chromosome> {
+        (0..size)
+            .map(|idx| {
+                let mut chromosome = self.seeds[idx % self.seeds.len()].clone();
+
+                if idx >= self.seeds.len() {
+                    for gene in chromosome.iter_mut() {
+                        *gene += rng.gen_range(-1.0..=1.0) * self.perturbation;
+                    }
+
+                    if let Some(bounds) = &self.bounds {
+                        ClampRepair.repair(rng, bounds, &mut chromosome);
+                    }
+                }
+
+                chromosome
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn seeded_initialization() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let seeds: Vec<Chromosome> = vec![
+            vec![1.0, 1.0].into_iter().collect(),
+            vec![5.0, 5.0].into_iter().collect(),
+        ];
+
+        let population = SeededInitialization::new(seeds.clone(), 0.1).initialize(&mut rng, 6);
+
+        assert_eq!(population[0], seeds[0]);
+        assert_eq!(population[1], seeds[1]);
+
+        for (idx, chromosome) in population.iter().enumerate().skip(2) {
+            let seed = &seeds[idx % 2];
+
+            assert_ne!(chromosome, seed);
+            assert!(chromosome.iter().zip(seed.iter()).all(|(a, b)| (a - b).abs() <= 0.1));
+        }
+    }
+
+    #[test]
+    fn seeded_initialization_with_bounds() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let bounds = Bounds::uniform(0.0, 1.0);
+        let seeds: Vec<Chromosome> = vec![vec![0.0, 1.0].into_iter().collect()];
+
+        let population = SeededInitialization::new(seeds, 0.5).with_bounds(bounds.clone()).initialize(&mut rng, 20);
+
+        assert!(population.iter().all(|chromosome| bounds.contains(chromosome)));
+        assert!(population.iter().skip(1).any(|chromosome| chromosome[0] > 0.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates() {
+        let initialization: SeededInitialization = serde_json::from_str(r#"{"seeds":[[1.0]],"perturbation":0.1}"#).unwrap();
+
+        assert!(initialization.bounds.is_none());
+
+        for json in [
+            r#"{"seeds":[],"perturbation":0.1}"#,
+            r#"{"seeds":[[1.0]],"perturbation":-0.1}"#,
+            r#"{"seeds":[[1.0]],"perturbation":0.1,"bounds":{"ranges":{"PerGene":[[0.0,1.0],[0.0,1.0]]}}}"#,
+        ] {
+            assert!(serde_json::from_str::<SeededInitialization>(json).is_err(), "{}", json);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawUniformInitialization"))]
+pub struct UniformInitialization {
+    bounds: Bounds,
+    genes: usize,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawUniformInitialization {
+    bounds: Bounds,
+    genes: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawUniformInitialization> for UniformInitialization {
+    type Error = GaError;
+
+    fn try_from(raw: RawUniformInitialization) -> Result<Self, Self::Error> {
+        raw.bounds.try_check_len(raw.genes)?;
+
+        Ok(Self { bounds: raw.bounds, genes: raw.genes })
+    }
+}
+
+impl UniformInitialization {
+    pub fn new(bounds: Bounds, genes: usize) -> Self {
+        bounds.try_check_len(genes).unwrap_or_else(|err| panic!("{}", err));
+
+        Self { bounds, genes }
+    }
+}
+
+impl InitializationMethod for UniformInitialization {
+    fn initialize(&self, rng: &mut dyn RngCore, size: usize) -> Vec<Chromosome> {
+        (0..size)
+            .map(|_| {
+                (0..self.genes)
+                    .map(|gene| {
+                        let (lower, upper) = self.bounds.range(gene);
+
+                        rng.gen_range(lower..=upper)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn uniform_initialization() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let bounds = Bounds::per_gene([(0.0, 1.0), (-10.0, -5.0), (3.0, 3.0)]);
+
+        let population = UniformInitialization::new(bounds.clone(), 3).initialize(&mut rng, 50);
+
+        assert_eq!(population.len(), 50);
+        assert!(population.iter().all(|chromosome| bounds.contains(chromosome)));
+        assert!(population.iter().all(|chromosome| chromosome[2] == 3.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a chromosome with 2 genes, got 3")]
+    fn mismatched_bounds() {
+        UniformInitialization::new(Bounds::per_gene([(0.0, 1.0), (0.0, 1.0)]), 3);
+    }
+}
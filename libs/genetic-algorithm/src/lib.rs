@@ -1,13 +1,40 @@
+pub mod benchmarks;
+mod bounds;
 mod crossover;
+mod error;
+mod evaluation;
+mod evaluator;
+mod hall_of_fame;
 mod chromosome;
+mod coevolution;
+mod immigration;
 mod individual;
+mod initialization;
+mod local_search;
 mod mutation;
+mod penalty;
+#[cfg(feature = "serde")]
+mod population;
+mod repair;
 mod selection;
 
+pub use self::bounds::*;
 pub use self::crossover::*;
+pub use self::error::*;
+pub use self::evaluation::*;
+pub use self::evaluator::*;
+pub use self::hall_of_fame::*;
 pub use self::chromosome::*;
+pub use self::coevolution::*;
+pub use self::immigration::*;
 pub use self::individual::*;
+pub use self::initialization::*;
+pub use self::local_search::*;
 pub use self::mutation::*;
+pub use self::penalty::*;
+#[cfg(feature = "serde")]
+pub use self::population::*;
+pub use self::repair::*;
 pub use self::selection::*;
 use rand::{seq::SliceRandom, Rng, RngCore};
 
@@ -15,6 +42,11 @@ pub struct GeneticAlgorithm<S> {
     selection_method: S,
     crossover_method: Box<dyn CrossoverMethod>,
     mutation_method: Box<dyn MutationMethod>,
+    bounds: Option<Bounds>,
+    repair_method: Box<dyn RepairMethod>,
+    invalid_fitness_policy: InvalidFitnessPolicy,
+    local_search: Option<(Box<dyn LocalSearchMethod>, Inheritance)>,
+    immigration: Option<RandomImmigrants>,
 }
 
 impl<S> GeneticAlgorithm<S> where S: SelectionMethod, {
@@ -24,42 +56,194 @@ impl<S> GeneticAlgorithm<S> where S: SelectionMethod, {
         mutation_method: impl MutationMethod + 'static) -> Self {
         Self {  selection_method,
                 crossover_method: Box::new(crossover_method), 
-                mutation_method: Box::new(mutation_method) 
+                mutation_method: Box::new(mutation_method),
+                bounds: None,
+                repair_method: Box::new(ClampRepair),
+                invalid_fitness_policy: InvalidFitnessPolicy::default(),
+                local_search: None,
+                immigration: None,
              }
     }
 
-    pub fn evolve<I>(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics) where I: Individual {
-        assert!{!population.is_empty()};
+    pub fn with_bounds(mut self, bounds: Bounds) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    pub fn with_repair_method(mut self, repair_method: impl RepairMethod + 'static) -> Self {
+        self.repair_method = Box::new(repair_method);
+        self
+    }
+
+    pub fn with_invalid_fitness_policy(mut self, invalid_fitness_policy: InvalidFitnessPolicy) -> Self {
+        self.invalid_fitness_policy = invalid_fitness_policy;
+        self
+    }
+
+    /// Refines every offspring with local search before it joins the new
+    /// population, using the evaluator passed to `evolve_with` to judge
+    /// candidates.
+    pub fn with_local_search(mut self, local_search: impl LocalSearchMethod + 'static, inheritance: Inheritance) -> Self {
+        self.local_search = Some((Box::new(local_search), inheritance));
+        self
+    }
+
+    /// Replaces some of the offspring with random immigrants whenever the
+    /// `generation` passed to `evolve` is due.
+    pub fn with_immigration(mut self, immigration: RandomImmigrants) -> Self {
+        self.immigration = Some(immigration);
+        self
+    }
+
+    /// `generation` counts the generations bred so far; it only decides
+    /// when immigrants arrive.
+    pub fn evolve<I>(&self, rng: &mut dyn RngCore, population: &[I], generation: usize) -> (Vec<I>, Statistics) where I: Individual {
+        self.try_evolve(rng, population, generation).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_evolve<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+        generation: usize,
+    ) -> Result<(Vec<I>, Statistics), GaError> where I: Individual {
+        self.try_evolve_with(rng, population, generation, &mut FitnessEvaluator)
+    }
+
+    pub fn evolve_with<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+        generation: usize,
+        evaluator: &mut dyn Evaluator<I>,
+    ) -> (Vec<I>, Statistics) where I: Individual {
+        self.try_evolve_with(rng, population, generation, evaluator).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_evolve_with<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+        generation: usize,
+        evaluator: &mut dyn Evaluator<I>,
+    ) -> Result<(Vec<I>, Statistics), GaError> where I: Individual {
+        if population.is_empty() {
+            return Err(GaError::EmptyPopulation);
+        }
+
+        let fitnesses = evaluation::fitnesses(population, evaluator, &self.invalid_fitness_policy)?;
+
+        self.evolve_scored(rng, population, generation, fitnesses, evaluator)
+    }
+
+    /// Breeds the next generation out of fitnesses that were already
+    /// computed; `evaluator` is only consulted by local search.
+    pub(crate) fn evolve_scored<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+        generation: usize,
+        fitnesses: Vec<Option<f32>>,
+        evaluator: &mut dyn Evaluator<I>,
+    ) -> Result<(Vec<I>, Statistics), GaError> where I: Individual {
+        let (evaluated, invalid_count) = evaluation::assemble(population, fitnesses, &self.invalid_fitness_policy)?;
+
+        let mut stats = Statistics::try_new(&evaluated)?;
+        stats.invalid_count = invalid_count;
 
-        let new_population: Vec<I>  = (0..population.len())
+        let mut new_population: Vec<I>  = (0..population.len())
             .map(|_| {
-                let parent_a = self.selection_method.select(rng, population).chromosome();
-                let parent_b = self.selection_method.select(rng, population).chromosome();
+                let parent_a = self.selection_method.try_select(rng, &evaluated)?.chromosome();
+                let parent_b = self.selection_method.try_select(rng, &evaluated)?.chromosome();
                 
-                let mut child = self.crossover_method.crossover(rng, parent_a, parent_b);
+                let mut child = self.crossover_method.try_crossover(rng, parent_a, parent_b)?;
 
                 self.mutation_method.mutate(rng, &mut child);
 
-                I::create(child)
+                if let Some(bounds) = &self.bounds {
+                    bounds.try_check(&child)?;
+                    self.repair_method.repair(rng, bounds, &mut child);
+                }
+
+                Ok(self.refine(rng, child, evaluator))
             })
-            .collect();
+            .collect::<Result<_, GaError>>()?;
+
+        if let Some(immigration) = &self.immigration {
+            immigration.immigrate(rng, &mut new_population, generation);
+        }
+
+        Ok((new_population, stats))
+    }
+
+    fn refine<I>(&self, rng: &mut dyn RngCore, mut child: Chromosome, evaluator: &mut dyn Evaluator<I>) -> I where I: Individual {
+        let Some((local_search, inheritance)) = &self.local_search else {
+            return I::create(child);
+        };
+
+        // Offspring that can't be evaluated join the population unrefined
+        let Some(fitness) = self.evaluate_candidate(rng, &mut child, evaluator) else {
+            return I::create(child);
+        };
+
+        let mut refined = child.clone();
+
+        let learned_fitness = local_search.search(
+            rng,
+            &mut refined,
+            fitness,
+            &mut |rng, candidate| self.evaluate_candidate(rng, candidate, evaluator),
+        );
 
-        let stats = Statistics::new(population);
+        match inheritance {
+            Inheritance::Lamarckian => I::create(refined),
+            Inheritance::Baldwinian => {
+                let mut individual = I::create(child);
+                individual.set_learned_fitness(learned_fitness);
+                individual
+            }
+        }
+    }
+
+    fn evaluate_candidate<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        candidate: &mut Chromosome,
+        evaluator: &mut dyn Evaluator<I>,
+    ) -> Option<f32> where I: Individual {
+        if let Some(bounds) = &self.bounds {
+            // A candidate that doesn't fit the bounds can't be repaired, so it counts as unevaluated
+            bounds.try_check(candidate).ok()?;
+            self.repair_method.repair(rng, bounds, candidate);
+        }
+
+        let individual = I::create(candidate.clone());
 
-        (new_population, stats)
+        evaluator
+            .try_evaluate(&[&individual])
+            .ok()?
+            .pop()
+            .flatten()
+            .filter(|fitness| fitness.is_finite())
     }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Statistics {
     pub min_fitness: f32,
     pub max_fitness: f32,
     pub avg_fitness: f32,
+    /// Missing from statistics saved before invalid fitness was tracked.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub invalid_count: usize,
 }
 
 impl Statistics {
-    fn new<I>(population: &[I]) -> Self where I: Individual {
-        assert!(!population.is_empty());
+    pub fn try_new<I>(population: &[I]) -> Result<Self, GaError> where I: Individual {
+        if population.is_empty() {
+            return Err(GaError::EmptyPopulation);
+        }
 
         let mut min_fitness = population[0].fitness();
         let mut max_fitness = min_fitness;
@@ -68,14 +252,18 @@ impl Statistics {
         for individual in population {
             let fitness = individual.fitness();
 
+            if !fitness.is_finite() {
+                return Err(GaError::InvalidFitness);
+            }
+
             min_fitness = min_fitness.min(fitness);
             max_fitness = max_fitness.max(fitness);
-            sum_fitness = sum_fitness + fitness;
+            sum_fitness += fitness;
         }
 
         let avg_fitness = sum_fitness / population.len() as f32;
 
-        Self { min_fitness, max_fitness, avg_fitness }
+        Ok(Self { min_fitness, max_fitness, avg_fitness, invalid_count: 0 })
     }
 }
 
@@ -107,8 +295,8 @@ mod tests {
             individual(&[1.0, 2.0, 4.0]),
         ];
 
-        for _ in 0..10 {
-            (population, _) = ga.evolve(&mut rng, &population);
+        for generation in 0..10 {
+            (population, _) = ga.evolve(&mut rng, &population, generation);
         }
 
         let expected_population = vec![
@@ -121,4 +309,263 @@ mod tests {
         assert_eq!(population, expected_population);
     }
 
-}
\ No newline at end of file
+    #[cfg(feature = "serde")]
+    #[test]
+    fn statistics_roundtrip() {
+        let stats = Statistics::try_new(&[TestIndividual::new(1.0), TestIndividual::new(3.0)]).unwrap();
+        let json = serde_json::to_string(&stats).unwrap();
+
+        assert_eq!(json, r#"{"min_fitness":1.0,"max_fitness":3.0,"avg_fitness":2.0,"invalid_count":0}"#);
+
+        let stats: Statistics = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(stats.max_fitness, 3.0);
+
+        let stats: Statistics = serde_json::from_str(r#"{"min_fitness":1.0,"max_fitness":3.0,"avg_fitness":2.0}"#).unwrap();
+
+        assert_eq!(stats.invalid_count, 0);
+    }
+
+    #[test]
+    fn genetic_algorithm_with_invalid_individuals() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection,
+            UniformCrossover,
+            GaussianMutation::new(0.5, 0.5)
+        )
+        .with_invalid_fitness_policy(InvalidFitnessPolicy::Discard);
+
+        let population = vec![
+            individual(&[1.0, 1.0, 1.0]),
+            individual(&[f32::NAN, 2.0, 1.0]),
+            individual(&[1.0, 2.0, 4.0]),
+            individual(&[f32::INFINITY, 0.0, 0.0]),
+        ];
+
+        let (population, stats) = ga.evolve(&mut rng, &population, 0);
+
+        assert_eq!(population.len(), 4);
+        assert_eq!(stats.invalid_count, 2);
+        assert_eq!(stats.min_fitness, 3.0);
+        assert_eq!(stats.max_fitness, 7.0);
+        assert!(population.iter().all(|individual| individual.fitness().is_finite()));
+    }
+
+    #[test]
+    fn genetic_algorithm_with_evaluator() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection,
+            UniformCrossover,
+            GaussianMutation::new(0.0, 0.0)
+        );
+
+        let mut population = vec![
+            individual(&[1.0, 1.0, 1.0]),
+            individual(&[1.0, 2.0, 1.0]),
+            individual(&[1.0, 2.0, 4.0]),
+        ];
+
+        let mut evaluated = 0;
+
+        let mut evaluator = CachedEvaluator::new(|individuals: &[&TestIndividual]| {
+            evaluated += individuals.len();
+
+            individuals
+                .iter()
+                .map(|individual| Some(individual.fitness()))
+                .collect()
+        });
+
+        for generation in 0..10 {
+            let (new_population, stats) = ga.evolve_with(&mut rng, &population, generation, &mut evaluator);
+
+            assert!(stats.max_fitness <= 7.0);
+
+            population = new_population;
+        }
+
+        // Without mutation, crossover can only ever produce 4 distinct
+        // chromosomes out of these parents
+        assert!(evaluator.len() <= 4);
+
+        drop(evaluator);
+
+        assert_eq!(evaluated, 4);
+    }
+
+    fn sphere_population(rng: &mut dyn RngCore) -> Vec<benchmarks::Sphere> {
+        (0..20)
+            .map(|_| benchmarks::Sphere::create((0..3).map(|_| rng.gen_range(-5.0..=5.0)).collect()))
+            .collect()
+    }
+
+    fn avg_fitness(population: &[benchmarks::Sphere]) -> f32 {
+        population.iter().map(|individual| individual.fitness()).sum::<f32>() / population.len() as f32
+    }
+
+    #[test]
+    fn genetic_algorithm_with_lamarckian_local_search() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = sphere_population(&mut rng);
+
+        let plain = GeneticAlgorithm::new(RouletteWheelSelection, UniformCrossover, GaussianMutation::new(0.1, 0.1));
+
+        let memetic = GeneticAlgorithm::new(RouletteWheelSelection, UniformCrossover, GaussianMutation::new(0.1, 0.1))
+            .with_local_search(HillClimbing::new(20, 0.2), Inheritance::Lamarckian);
+
+        let (plain_offspring, plain_stats) = plain.evolve(&mut rng.clone(), &population, 0);
+        let (memetic_offspring, memetic_stats) = memetic.evolve(&mut rng, &population, 0);
+
+        assert!(avg_fitness(&memetic_offspring) > avg_fitness(&plain_offspring));
+        approx::assert_relative_eq!(memetic_stats.avg_fitness, plain_stats.avg_fitness);
+    }
+
+    /// Sphere individual that reports the fitness local search handed it.
+    struct Learner {
+        chromosome: Chromosome,
+        learned_fitness: Option<f32>,
+    }
+
+    impl Individual for Learner {
+        fn create(chromosome: Chromosome) -> Self {
+            Self { chromosome, learned_fitness: None }
+        }
+
+        fn fitness(&self) -> f32 {
+            self.learned_fitness.unwrap_or_else(|| benchmarks::Sphere::create(self.chromosome.clone()).fitness())
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            &self.chromosome
+        }
+
+        fn set_learned_fitness(&mut self, fitness: f32) {
+            self.learned_fitness = Some(fitness);
+        }
+    }
+
+    #[test]
+    fn genetic_algorithm_with_baldwinian_local_search() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population: Vec<_> = sphere_population(&mut rng)
+            .iter()
+            .map(|individual| Learner::create(individual.chromosome().clone()))
+            .collect();
+
+        let memetic = GeneticAlgorithm::new(RouletteWheelSelection, UniformCrossover, GaussianMutation::new(0.0, 0.0))
+            .with_local_search(HillClimbing::new(20, 0.2), Inheritance::Baldwinian);
+
+        let (offspring, _) = memetic.evolve(&mut rng, &population, 0);
+
+        // Without mutation, offspring genes still come straight from their
+        // parents...
+        assert!(offspring.iter().all(|child| {
+            child.chromosome().iter().enumerate().all(|(gene, value)| {
+                population.iter().any(|parent| parent.chromosome()[gene] == *value)
+            })
+        }));
+
+        // ...but they carry the fitness local search reached from them
+        assert!(offspring.iter().all(|child| {
+            let raw_fitness = benchmarks::Sphere::create(child.chromosome().clone()).fitness();
+
+            child.learned_fitness.is_some_and(|fitness| fitness >= raw_fitness)
+        }));
+        assert!(offspring.iter().any(|child| child.fitness() > benchmarks::Sphere::create(child.chromosome().clone()).fitness()));
+    }
+
+    #[test]
+    fn genetic_algorithm_with_immigration() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection,
+            UniformCrossover,
+            GaussianMutation::new(0.0, 0.0)
+        )
+        .with_immigration(RandomImmigrants::new(UniformInitialization::new(Bounds::uniform(10.0, 20.0), 3), 1, 5));
+
+        let mut population = vec![
+            individual(&[1.0, 1.0, 1.0]),
+            individual(&[1.0, 2.0, 1.0]),
+            individual(&[1.0, 2.0, 4.0]),
+        ];
+
+        (population, _) = ga.evolve(&mut rng, &population, 3);
+
+        assert!(population.iter().all(|individual| individual.chromosome()[0] == 1.0));
+
+        (population, _) = ga.evolve(&mut rng, &population, 4);
+
+        assert_eq!(population.iter().filter(|individual| individual.chromosome()[0] >= 10.0).count(), 1);
+    }
+
+    #[test]
+    fn genetic_algorithm_with_bounds() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let bounds = Bounds::uniform(0.0, 2.0);
+
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection,
+            UniformCrossover,
+            GaussianMutation::new(0.5, 0.5)
+        )
+        .with_bounds(bounds.clone())
+        .with_repair_method(ReflectRepair);
+
+        let mut population = vec![
+            individual(&[0.0, 0.0, 0.0]),
+            individual(&[1.0, 1.0, 1.0]),
+            individual(&[1.0, 2.0, 1.0]),
+            individual(&[1.0, 2.0, 2.0]),
+        ];
+
+        for generation in 0..50 {
+            (population, _) = ga.evolve(&mut rng, &population, generation);
+
+            assert!(population.iter().all(|individual| bounds.contains(individual.chromosome())));
+        }
+    }
+
+    #[test]
+    fn genetic_algorithm_errors() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection,
+            UniformCrossover,
+            GaussianMutation::new(0.5, 0.5)
+        )
+        .with_bounds(Bounds::per_gene([(0.0, 1.0), (0.0, 1.0)]));
+
+        let population: Vec<TestIndividual> = vec![];
+        assert_eq!(ga.try_evolve(&mut rng, &population, 0).err(), Some(GaError::EmptyPopulation));
+
+        let population = vec![individual(&[0.0, 0.0]), individual(&[0.0, 0.0])];
+        assert_eq!(ga.try_evolve(&mut rng, &population, 0).err(), Some(GaError::ZeroTotalFitness));
+
+        let population = vec![individual(&[1.0, 1.0, 1.0])];
+        assert_eq!(
+            ga.try_evolve(&mut rng, &population, 0).err(),
+            Some(GaError::LengthMismatch { expected: 2, actual: 3 }),
+        );
+
+        // Local search refines offspring, and mustn't panic on them either
+        let ga = ga.with_local_search(HillClimbing::new(5, 0.1), Inheritance::Lamarckian);
+        assert_eq!(
+            ga.try_evolve(&mut rng, &population, 0).err(),
+            Some(GaError::LengthMismatch { expected: 2, actual: 3 }),
+        );
+
+        let population = vec![individual(&[0.5, 0.5]), individual(&[1.0, 0.5])];
+        let mut evaluator = |_: &[&TestIndividual]| vec![Some(1.0)];
+        assert_eq!(
+            ga.try_evolve_with(&mut rng, &population, 0, &mut evaluator).err(),
+            Some(GaError::LengthMismatch { expected: 2, actual: 1 }),
+        );
+    }
+}
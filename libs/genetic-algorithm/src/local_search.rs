@@ -0,0 +1,41 @@
+mod hill_climbing;
+mod random_restart;
+
+pub use self::hill_climbing::*;
+pub use self::random_restart::*;
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Inheritance {
+    /// Offspring are replaced with their refined chromosomes.
+    Lamarckian,
+    /// Offspring keep their chromosomes; only the fitness they reached is
+    /// handed over, through `Individual::set_learned_fitness`.
+    Baldwinian,
+}
+
+/// Callback given to local search: it may repair the candidate in place
+/// and returns its fitness, or `None` when it could not be evaluated.
+pub type CandidateEvaluator<'a> = dyn FnMut(&mut dyn RngCore, &mut Chromosome) -> Option<f32> + 'a;
+
+pub trait LocalSearchMethod {
+    /// Refines `chromosome` in place, starting from the given fitness, and
+    /// returns the fitness of the refined chromosome.
+    fn search(
+        &self,
+        rng: &mut dyn RngCore,
+        chromosome: &mut Chromosome,
+        fitness: f32,
+        evaluate: &mut CandidateEvaluator,
+    ) -> f32;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn sphere(_rng: &mut dyn RngCore, chromosome: &mut Chromosome) -> Option<f32> {
+        Some(1.0 / (1.0 + chromosome.iter().map(|x| x * x).sum::<f32>()))
+    }
+}
@@ -0,0 +1,75 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HillClimbing {
+    steps: usize,
+    step_size: f32,
+}
+
+impl HillClimbing {
+    pub fn new(steps: usize, step_size: f32) -> Self {
+        assert!(step_size >= 0.0);
+
+        Self { steps, step_size }
+    }
+}
+
+impl LocalSearchMethod for HillClimbing {
+    fn search(
+        &self,
+        rng: &mut dyn RngCore,
+        chromosome: &mut Chromosome,
+        mut fitness: f32,
+        evaluate: &mut CandidateEvaluator,
+    ) -> f32 {
+        for _ in 0..self.steps {
+            let mut candidate = chromosome.clone();
+
+            for gene in candidate.iter_mut() {
+                *gene += rng.gen_range(-1.0..=1.0) * self.step_size;
+            }
+
+            if let Some(candidate_fitness) = evaluate(rng, &mut candidate) {
+                if candidate_fitness > fitness {
+                    *chromosome = candidate;
+                    fitness = candidate_fitness;
+                }
+            }
+        }
+
+        fitness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local_search::tests::sphere;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn hill_climbing() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut chromosome: Chromosome = vec![1.0, -1.0, 0.5].into_iter().collect();
+        let start = sphere(&mut rng, &mut chromosome).unwrap();
+
+        let fitness = HillClimbing::new(50, 0.1).search(&mut rng, &mut chromosome, start, &mut sphere);
+
+        assert!(fitness > start);
+        assert_relative_eq!(fitness, sphere(&mut rng, &mut chromosome).unwrap());
+    }
+
+    #[test]
+    fn hill_climbing_without_steps() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut chromosome: Chromosome = vec![1.0, -1.0, 0.5].into_iter().collect();
+
+        let fitness = HillClimbing::new(0, 0.1).search(&mut rng, &mut chromosome, 0.25, &mut sphere);
+
+        assert_eq!(fitness, 0.25);
+        assert_eq!(chromosome, vec![1.0, -1.0, 0.5].into_iter().collect());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::*;
+
+/// Runs hill climbing several times, each time from a random point within
+/// `radius` of the original chromosome, and keeps the best result.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RandomRestartHillClimbing {
+    restarts: usize,
+    radius: f32,
+    hill_climbing: HillClimbing,
+}
+
+impl RandomRestartHillClimbing {
+    pub fn new(restarts: usize, radius: f32, steps: usize, step_size: f32) -> Self {
+        assert!(radius >= 0.0);
+
+        Self { restarts, radius, hill_climbing: HillClimbing::new(steps, step_size) }
+    }
+}
+
+impl LocalSearchMethod for RandomRestartHillClimbing {
+    fn search(
+        &self,
+        rng: &mut dyn RngCore,
+        chromosome: &mut Chromosome,
+        fitness: f32,
+        evaluate: &mut CandidateEvaluator,
+    ) -> f32 {
+        let origin = chromosome.clone();
+        let mut best_fitness = self.hill_climbing.search(rng, chromosome, fitness, evaluate);
+
+        for _ in 0..self.restarts {
+            let mut candidate = origin.clone();
+
+            for gene in candidate.iter_mut() {
+                *gene += rng.gen_range(-1.0..=1.0) * self.radius;
+            }
+
+            let Some(candidate_fitness) = evaluate(rng, &mut candidate) else {
+                continue;
+            };
+
+            let candidate_fitness = self.hill_climbing.search(rng, &mut candidate, candidate_fitness, evaluate);
+
+            if candidate_fitness > best_fitness {
+                *chromosome = candidate;
+                best_fitness = candidate_fitness;
+            }
+        }
+
+        best_fitness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local_search::tests::sphere;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn random_restart_hill_climbing() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut chromosome: Chromosome = vec![3.0, -3.0, 2.0].into_iter().collect();
+        let start = sphere(&mut rng, &mut chromosome).unwrap();
+
+        let single = {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut chromosome = chromosome.clone();
+
+            HillClimbing::new(10, 0.1).search(&mut rng, &mut chromosome, start, &mut sphere)
+        };
+
+        let fitness = RandomRestartHillClimbing::new(5, 3.0, 10, 0.1)
+            .search(&mut rng, &mut chromosome, start, &mut sphere);
+
+        assert!(fitness > single);
+        assert_relative_eq!(fitness, sphere(&mut rng, &mut chromosome).unwrap());
+    }
+}
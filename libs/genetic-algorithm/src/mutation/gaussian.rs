@@ -1,19 +1,51 @@
 use crate::*;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawGaussianMutation"))]
 pub struct GaussianMutation {
     mutation_probability: f32,
     mutation_coefficient: f32,
 }
 
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawGaussianMutation {
+    mutation_probability: f32,
+    mutation_coefficient: f32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawGaussianMutation> for GaussianMutation {
+    type Error = GaError;
+
+    fn try_from(raw: RawGaussianMutation) -> Result<Self, Self::Error> {
+        validate(raw.mutation_probability, raw.mutation_coefficient)?;
+
+        Ok(Self { mutation_probability: raw.mutation_probability, mutation_coefficient: raw.mutation_coefficient })
+    }
+}
+
 impl GaussianMutation {
     pub fn new(mutation_probability: f32, mutation_coefficient: f32) -> Self {
-        assert!(mutation_probability >= 0.0 && mutation_coefficient <= 1.0);
+        validate(mutation_probability, mutation_coefficient).unwrap_or_else(|err| panic!("{}", err));
 
         Self { mutation_probability, mutation_coefficient }
     }
 }
 
+fn validate(mutation_probability: f32, mutation_coefficient: f32) -> Result<(), GaError> {
+    if !(0.0..=1.0).contains(&mutation_probability) {
+        return Err(GaError::InvalidConfig("mutation probability must be within 0..=1"));
+    }
+
+    if mutation_coefficient.is_nan() || mutation_coefficient > 1.0 {
+        return Err(GaError::InvalidConfig("mutation coefficient must not exceed 1"));
+    }
+
+    Ok(())
+}
+
 impl MutationMethod for GaussianMutation {
     fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
         for gene in child.iter_mut() {
@@ -39,6 +71,19 @@ mod tests {
         child.into_iter().collect()
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates() {
+        let json = |chance: f32, coeff: f32| {
+            format!(r#"{{"mutation_probability":{},"mutation_coefficient":{}}}"#, chance, coeff)
+        };
+
+        assert!(serde_json::from_str::<GaussianMutation>(&json(0.5, 0.5)).is_ok());
+        assert!(serde_json::from_str::<GaussianMutation>(&json(1.5, 0.5)).is_err());
+        assert!(serde_json::from_str::<GaussianMutation>(&json(-0.5, 0.5)).is_err());
+        assert!(serde_json::from_str::<GaussianMutation>(&json(0.5, 1.5)).is_err());
+    }
+
     mod given_zero_chance {
         use approx::assert_relative_eq;
 
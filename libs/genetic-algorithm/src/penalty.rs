@@ -0,0 +1,100 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawPenalty"))]
+pub struct Penalty {
+    coefficient: f32,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawPenalty {
+    coefficient: f32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawPenalty> for Penalty {
+    type Error = GaError;
+
+    fn try_from(raw: RawPenalty) -> Result<Self, Self::Error> {
+        validate(raw.coefficient)?;
+
+        Ok(Self { coefficient: raw.coefficient })
+    }
+}
+
+impl Penalty {
+    pub fn new(coefficient: f32) -> Self {
+        validate(coefficient).unwrap_or_else(|err| panic!("{}", err));
+
+        Self { coefficient }
+    }
+
+    /// Violations follow the `g(x) <= 0` convention: anything positive is the
+    /// amount by which a constraint is broken, anything else is satisfied.
+    ///
+    /// Non-negative fitness is divided by `1 + coefficient * violation` rather
+    /// than having the penalty subtracted, so it stays a valid weight for
+    /// `RouletteWheelSelection`; negative fitness is multiplied instead, so
+    /// worse violations always rank lower.
+    pub fn apply(&self, fitness: f32, violations: impl IntoIterator<Item = f32>) -> f32 {
+        let violation: f32 = violations
+            .into_iter()
+            .map(|violation| violation.max(0.0))
+            .sum();
+
+        let factor = 1.0 + self.coefficient * violation;
+
+        if fitness < 0.0 {
+            fitness * factor
+        } else {
+            fitness / factor
+        }
+    }
+}
+
+fn validate(coefficient: f32) -> Result<(), GaError> {
+    if coefficient.is_nan() || coefficient < 0.0 {
+        return Err(GaError::InvalidConfig("penalty coefficient must be non-negative"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn satisfied_constraints_do_not_change_fitness() {
+        assert_relative_eq!(Penalty::new(10.0).apply(5.0, [0.0, -2.0]), 5.0);
+    }
+
+    #[test]
+    fn violated_constraints_reduce_fitness() {
+        assert_relative_eq!(Penalty::new(2.0).apply(5.0, [0.5, -2.0, 1.0]), 1.25);
+    }
+
+    #[test]
+    fn penalized_fitness_is_never_negative() {
+        assert!(Penalty::new(100.0).apply(5.0, [1.0]) > 0.0);
+    }
+
+    #[test]
+    fn larger_violations_rank_lower() {
+        let penalty = Penalty::new(100.0);
+
+        assert!(penalty.apply(5.0, [1.0]) > penalty.apply(5.0, [2.0]));
+        assert!(penalty.apply(-5.0, [0.0]) > penalty.apply(-5.0, [1.0]));
+        assert_relative_eq!(penalty.apply(-5.0, [1.0]), -505.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates() {
+        assert!(serde_json::from_str::<Penalty>(r#"{"coefficient":2.0}"#).is_ok());
+        assert!(serde_json::from_str::<Penalty>(r#"{"coefficient":-1.0}"#).is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::*;
+use std::io::{self, Read, Write};
+
+pub fn save_population<I>(writer: impl Write, population: &[I]) -> io::Result<()> where I: Individual {
+    let chromosomes: Vec<_> = population
+        .iter()
+        .map(|individual| individual.chromosome())
+        .collect();
+
+    serde_json::to_writer(writer, &chromosomes)?;
+
+    Ok(())
+}
+
+pub fn load_population<I>(reader: impl Read) -> io::Result<Vec<I>> where I: Individual {
+    let chromosomes: Vec<Chromosome> = serde_json::from_reader(reader)?;
+
+    Ok(chromosomes.into_iter().map(I::create).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_and_load_population() {
+        let population: Vec<TestIndividual> = vec![
+            TestIndividual::create(vec![0.0, 0.5, 1.0].into_iter().collect()),
+            TestIndividual::create(vec![-1.25, 2.0, 3.5].into_iter().collect()),
+        ];
+
+        let mut file = Vec::new();
+        save_population(&mut file, &population).unwrap();
+
+        assert_eq!(String::from_utf8(file.clone()).unwrap(), "[[0.0,0.5,1.0],[-1.25,2.0,3.5]]");
+
+        let loaded: Vec<TestIndividual> = load_population(file.as_slice()).unwrap();
+
+        assert_eq!(loaded, population);
+    }
+
+    #[test]
+    fn load_corrupt_population() {
+        let loaded = load_population::<TestIndividual>("[[0.0, 0.5".as_bytes());
+
+        assert_eq!(loaded.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
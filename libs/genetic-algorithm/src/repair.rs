@@ -0,0 +1,26 @@
+mod clamp;
+mod reflect;
+mod resample;
+mod wrap;
+
+pub use self::clamp::*;
+pub use self::reflect::*;
+pub use self::resample::*;
+pub use self::wrap::*;
+use crate::*;
+
+pub trait RepairMethod {
+    fn repair(&self, rng: &mut dyn RngCore, bounds: &Bounds, child: &mut Chromosome);
+}
+
+/// Where a non-finite gene lands: infinities go to the bound they point
+/// towards, NaN goes to the middle of the range.
+fn non_finite(value: f32, lower: f32, upper: f32) -> f32 {
+    if value == f32::INFINITY {
+        upper
+    } else if value == f32::NEG_INFINITY {
+        lower
+    } else {
+        lower + (upper - lower) / 2.0
+    }
+}
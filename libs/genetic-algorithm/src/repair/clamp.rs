@@ -0,0 +1,55 @@
+use super::non_finite;
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClampRepair;
+
+impl RepairMethod for ClampRepair {
+    fn repair(&self, _rng: &mut dyn RngCore, bounds: &Bounds, child: &mut Chromosome) {
+        bounds.check(child);
+
+        for (gene, value) in child.iter_mut().enumerate() {
+            let (lower, upper) = bounds.range(gene);
+
+            *value = if value.is_finite() {
+                value.clamp(lower, upper)
+            } else {
+                non_finite(*value, lower, upper)
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn clamp_repair() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child = vec![-3.5, -1.0, 0.25, 1.0, 2.5].into_iter().collect();
+
+        ClampRepair.repair(&mut rng, &Bounds::uniform(-1.0, 1.0), &mut child);
+
+        let actual: Vec<_> = child.into_iter().collect();
+        let expected = vec![-1.0, -1.0, 0.25, 1.0, 1.0];
+
+        assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn clamp_repair_non_finite() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child = vec![f32::NAN, f32::INFINITY, f32::NEG_INFINITY].into_iter().collect();
+
+        ClampRepair.repair(&mut rng, &Bounds::uniform(-1.0, 3.0), &mut child);
+
+        let actual: Vec<_> = child.into_iter().collect();
+
+        assert_eq!(actual, vec![1.0, 3.0, -1.0]);
+    }
+}
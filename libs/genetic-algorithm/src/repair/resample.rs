@@ -0,0 +1,52 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResampleRepair;
+
+impl RepairMethod for ResampleRepair {
+    fn repair(&self, rng: &mut dyn RngCore, bounds: &Bounds, child: &mut Chromosome) {
+        bounds.check(child);
+
+        for (gene, value) in child.iter_mut().enumerate() {
+            let (lower, upper) = bounds.range(gene);
+
+            // NaN compares false against both bounds, so it's caught separately
+            if !value.is_finite() || *value < lower || *value > upper {
+                *value = rng.gen_range(lower..=upper);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn resample_repair() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let bounds = Bounds::uniform(-1.0, 1.0);
+        let mut child = vec![-1.5, -1.0, 0.25, 1.0, 3.5].into_iter().collect();
+
+        ResampleRepair.repair(&mut rng, &bounds, &mut child);
+
+        assert!(bounds.contains(&child));
+        assert_eq!(child[1], -1.0);
+        assert_eq!(child[2], 0.25);
+        assert_eq!(child[3], 1.0);
+    }
+
+    #[test]
+    fn resample_repair_non_finite() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let bounds = Bounds::uniform(-1.0, 1.0);
+        let mut child = vec![f32::NAN, f32::INFINITY, f32::NEG_INFINITY].into_iter().collect();
+
+        ResampleRepair.repair(&mut rng, &bounds, &mut child);
+
+        assert!(bounds.contains(&child));
+    }
+}
@@ -0,0 +1,61 @@
+use super::non_finite;
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WrapRepair;
+
+impl RepairMethod for WrapRepair {
+    fn repair(&self, _rng: &mut dyn RngCore, bounds: &Bounds, child: &mut Chromosome) {
+        bounds.check(child);
+
+        for (gene, value) in child.iter_mut().enumerate() {
+            let (lower, upper) = bounds.range(gene);
+
+            if !value.is_finite() {
+                *value = non_finite(*value, lower, upper);
+            } else if *value < lower || *value > upper {
+                let width = upper - lower;
+
+                *value = if width == 0.0 {
+                    lower
+                } else {
+                    lower + (*value - lower).rem_euclid(width)
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn wrap_repair() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child = vec![-1.5, -1.0, 0.25, 1.0, 3.5].into_iter().collect();
+
+        WrapRepair.repair(&mut rng, &Bounds::uniform(-1.0, 1.0), &mut child);
+
+        let actual: Vec<_> = child.into_iter().collect();
+        let expected = vec![0.5, -1.0, 0.25, 1.0, -0.5];
+
+        assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn wrap_repair_non_finite() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child = vec![f32::NAN, f32::INFINITY, f32::NEG_INFINITY].into_iter().collect();
+
+        WrapRepair.repair(&mut rng, &Bounds::uniform(-1.0, 3.0), &mut child);
+
+        let actual: Vec<_> = child.into_iter().collect();
+
+        assert_eq!(actual, vec![1.0, 3.0, -1.0]);
+    }
+}
@@ -4,5 +4,9 @@ pub use self::roulette_wheel::*;
 use crate::*;
 
 pub trait SelectionMethod {
-    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I where I: Individual;
-}
\ No newline at end of file
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I where I: Individual {
+        self.try_select(rng, population).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> Result<&'a I, GaError> where I: Individual;
+}
@@ -1,13 +1,19 @@
 use crate::*;
+use rand::distributions::WeightedError;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RouletteWheelSelection;
 
 impl SelectionMethod for RouletteWheelSelection {
-    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I where I: Individual {
+    fn try_select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> Result<&'a I, GaError> where I: Individual {
         population
             .choose_weighted(rng, |individual| individual.fitness())
-            .expect("population is empty")
+            .map_err(|err| match err {
+                WeightedError::NoItem => GaError::EmptyPopulation,
+                WeightedError::AllWeightsZero => GaError::ZeroTotalFitness,
+                WeightedError::InvalidWeight | WeightedError::TooMany => GaError::InvalidFitness,
+            })
     }
 }
 
@@ -50,4 +56,17 @@ mod tests {
 
         assert_eq!(actual_histogram, expected_histogram);
     }
+
+    #[test]
+    fn roulette_wheel_selection_errors() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let select = |population: &[TestIndividual], rng: &mut ChaCha8Rng| {
+            RouletteWheelSelection.try_select(rng, population).err()
+        };
+
+        assert_eq!(select(&[], &mut rng), Some(GaError::EmptyPopulation));
+        assert_eq!(select(&[TestIndividual::new(0.0)], &mut rng), Some(GaError::ZeroTotalFitness));
+        assert_eq!(select(&[TestIndividual::new(-1.0)], &mut rng), Some(GaError::InvalidFitness));
+    }
 }
\ No newline at end of file
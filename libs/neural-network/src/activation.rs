@@ -0,0 +1,108 @@
+use crate::Float;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Activation {
+    #[default]
+    ReLU,
+    LeakyReLU(f32),
+    Sigmoid,
+    Tanh,
+    Identity,
+    Softsign,
+    Step,
+}
+
+impl Activation {
+    pub fn apply(self, x: f32) -> f32 {
+        self.apply_in(x)
+    }
+
+    pub fn derivative(self, x: f32) -> f32 {
+        self.derivative_in(x)
+    }
+
+    /// `apply` for any `Float`.
+    pub fn apply_in<F: Float>(self, x: F) -> F {
+        match self {
+            Self::ReLU => x.max(F::ZERO),
+            Self::LeakyReLU(slope) => if x >= F::ZERO { x } else { F::from_f32(slope) * x },
+            Self::Sigmoid => F::ONE / (F::ONE + (-x).exp()),
+            Self::Tanh => x.tanh(),
+            Self::Identity => x,
+            Self::Softsign => x / (F::ONE + x.abs()),
+            Self::Step => if x >= F::ZERO { F::ONE } else { F::ZERO },
+        }
+    }
+
+    pub fn derivative_in<F: Float>(self, x: F) -> F {
+        match self {
+            Self::ReLU => if x > F::ZERO { F::ONE } else { F::ZERO },
+            Self::LeakyReLU(slope) => if x >= F::ZERO { F::ONE } else { F::from_f32(slope) },
+            Self::Sigmoid => {
+                let y = self.apply_in(x);
+                y * (F::ONE - y)
+            }
+            Self::Tanh => F::ONE - x.tanh().powi(2),
+            Self::Identity => F::ONE,
+            Self::Softsign => F::ONE / (F::ONE + x.abs()).powi(2),
+            Self::Step => F::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn apply() {
+        let cases = [
+            (Activation::ReLU, [0.0, 0.0, 2.0]),
+            (Activation::LeakyReLU(0.1), [-0.2, 0.0, 2.0]),
+            (Activation::Sigmoid, [0.11920292, 0.5, 0.8807971]),
+            (Activation::Tanh, [-0.9640276, 0.0, 0.9640276]),
+            (Activation::Identity, [-2.0, 0.0, 2.0]),
+            (Activation::Softsign, [-2.0 / 3.0, 0.0, 2.0 / 3.0]),
+            (Activation::Step, [0.0, 1.0, 1.0]),
+        ];
+
+        for (activation, expected) in cases {
+            let actual: Vec<_> = [-2.0, 0.0, 2.0].iter().map(|&x| activation.apply(x)).collect();
+
+            assert_relative_eq!(actual.as_slice(), expected.as_ref());
+        }
+    }
+
+    #[test]
+    fn derivative() {
+        let activations = [
+            Activation::ReLU,
+            Activation::LeakyReLU(0.1),
+            Activation::Sigmoid,
+            Activation::Tanh,
+            Activation::Identity,
+            Activation::Softsign,
+            Activation::Step,
+        ];
+
+        for activation in activations {
+            for x in [-1.5, -0.5, 0.5, 1.5] {
+                let h = 1e-2;
+                let expected = (activation.apply(x + h) - activation.apply(x - h)) / (2.0 * h);
+
+                assert_relative_eq!(activation.derivative(x), expected, epsilon = 1e-3);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let json = serde_json::to_string(&Activation::LeakyReLU(0.01)).unwrap();
+
+        assert_eq!(json, r#"{"LeakyReLU":0.01}"#);
+        assert_eq!(serde_json::from_str::<Activation>(&json).unwrap(), Activation::LeakyReLU(0.01));
+    }
+}
@@ -0,0 +1,135 @@
+use crate::*;
+
+impl Network {
+    /// Returns the loss and its gradient with respect to every weight, in `weights()` order.
+    ///
+    /// Training is `f32`-only, like `Loss` and the optimizers; `Network<f64>` is for inference.
+    pub fn gradients(&self, inputs: &[f32], targets: &[f32], loss: Loss) -> (f32, Vec<f32>) {
+        self.try_gradients(inputs, targets, loss).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_gradients(&self, inputs: &[f32], targets: &[f32], loss: Loss) -> Result<(f32, Vec<f32>), NnError> {
+        let mut gradients = vec![0.0; self.parameter_count()];
+        let value = self.try_accumulate_gradients(inputs, targets, loss, &mut gradients)?;
+
+        Ok((value, gradients))
+    }
+
+    pub(crate) fn try_accumulate_gradients(
+        &self,
+        inputs: &[f32],
+        targets: &[f32],
+        loss: Loss,
+        gradients: &mut [f32],
+    ) -> Result<f32, NnError> {
+        if let Some(layer) = self.layers.iter().position(|layer| layer.kind.is_recurrent()) {
+            return Err(NnError::RecurrentLayer { layer: layer + 1 });
+        }
+
+        let expected = self.layers[0].input_size;
+        if inputs.len() != expected {
+            return Err(NnError::InputLength { expected, actual: inputs.len() });
+        }
+
+        let expected = self.layers.last().unwrap().output_size;
+        if targets.len() != expected {
+            return Err(NnError::TargetLength { expected, actual: targets.len() });
+        }
+
+        let expected = self.parameter_count();
+        if gradients.len() != expected {
+            return Err(NnError::ParameterCount { expected, actual: gradients.len() });
+        }
+
+        let mut activations = vec![inputs.to_vec()];
+        let mut weighted_sums = Vec::with_capacity(self.layers.len());
+
+        for layer in &self.layers {
+            let sums: Vec<f32> = layer.weighted_sums(activations.last().unwrap()).collect();
+
+            activations.push(sums.iter().map(|&sum| layer.activation.apply(sum)).collect());
+            weighted_sums.push(sums);
+        }
+
+        let outputs = activations.last().unwrap();
+        let value = loss.loss(outputs, targets);
+        let mut output_gradients = loss.gradient(outputs, targets);
+        let mut end = gradients.len();
+
+        for (i, layer) in self.layers.iter().enumerate().rev() {
+            let start = end - layer.weights.len();
+
+            output_gradients = layer.backward(
+                &activations[i],
+                &weighted_sums[i],
+                &output_gradients,
+                &mut gradients[start..end],
+            );
+
+            end = start;
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn gradients() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let layers = &[
+            LayerTopology::new(3),
+            LayerTopology::new(4).with_activation(Activation::Tanh),
+            LayerTopology::new(2).with_activation(Activation::Sigmoid),
+        ];
+        let network = Network::random(&mut rng, layers);
+        let (inputs, targets) = ([0.5, -1.0, 0.25], [1.0, 0.0]);
+
+        for loss in [Loss::MeanSquaredError, Loss::CrossEntropy] {
+            let (value, gradients) = network.gradients(&inputs, &targets, loss);
+            let weights: Vec<_> = network.weights().collect();
+
+            assert_relative_eq!(value, loss.loss(&network.propogate(inputs.to_vec()), &targets));
+
+            for (i, gradient) in gradients.iter().enumerate() {
+                let h = 1e-2;
+                let nudged = |delta: f32| {
+                    let mut weights = weights.clone();
+                    weights[i] += delta;
+
+                    let outputs = Network::from_weights(layers, weights).propogate(inputs.to_vec());
+                    loss.loss(&outputs, &targets)
+                };
+                let expected = (nudged(h) - nudged(-h)) / (2.0 * h);
+
+                assert_relative_eq!(*gradient, expected, epsilon = 1e-3);
+            }
+        }
+
+        assert_eq!(
+            network.try_gradients(&inputs[..2], &targets, Loss::MeanSquaredError).err(),
+            Some(NnError::InputLength { expected: 3, actual: 2 }),
+        );
+        assert_eq!(
+            network.try_gradients(&inputs, &targets[..1], Loss::MeanSquaredError).err(),
+            Some(NnError::TargetLength { expected: 2, actual: 1 }),
+        );
+    }
+
+    #[test]
+    fn gradients_of_recurrent_network() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let network = Network::random(&mut rng, &[LayerTopology::new(2), LayerTopology::new(2).with_kind(LayerKind::Elman)]);
+
+        assert_eq!(
+            network.try_gradients(&[0.0, 0.0], &[0.0, 0.0], Loss::MeanSquaredError).err(),
+            Some(NnError::RecurrentLayer { layer: 1 }),
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCtrnnTopology"))]
+pub struct CtrnnTopology {
+    inputs: usize,
+    neurons: usize,
+    /// Number of trailing neurons whose firing rates are the network's outputs.
+    outputs: usize,
+    step_size: f32,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawCtrnnTopology {
+    inputs: usize,
+    neurons: usize,
+    outputs: usize,
+    step_size: f32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCtrnnTopology> for CtrnnTopology {
+    type Error = &'static str;
+
+    fn try_from(raw: RawCtrnnTopology) -> Result<Self, Self::Error> {
+        if raw.outputs > raw.neurons {
+            return Err("outputs must not exceed neurons");
+        }
+
+        if raw.step_size.is_nan() || raw.step_size <= 0.0 {
+            return Err("step size must be positive");
+        }
+
+        Ok(Self { inputs: raw.inputs, neurons: raw.neurons, outputs: raw.outputs, step_size: raw.step_size })
+    }
+}
+
+impl CtrnnTopology {
+    pub fn new(inputs: usize, neurons: usize, outputs: usize) -> Self {
+        assert!(outputs <= neurons);
+
+        Self { inputs, neurons, outputs, step_size: 0.1 }
+    }
+
+    pub fn with_step_size(mut self, step_size: f32) -> Self {
+        assert!(step_size > 0.0);
+
+        self.step_size = step_size;
+        self
+    }
+
+    pub fn inputs(&self) -> usize {
+        self.inputs
+    }
+
+    pub fn neurons(&self) -> usize {
+        self.neurons
+    }
+
+    pub fn outputs(&self) -> usize {
+        self.outputs
+    }
+
+    pub fn step_size(&self) -> f32 {
+        self.step_size
+    }
+
+    fn row_len(&self) -> usize {
+        3 + self.inputs + self.neurons
+    }
+
+    pub fn weights_len(&self) -> usize {
+        self.neurons * self.row_len()
+    }
+}
+
+/// Continuous-time recurrent network integrated with a fixed-step Euler solver.
+///
+/// Each neuron is encoded as `[time constant, bias, gain, input weights.., recurrent weights..]`;
+/// time constants and gains are stored as logarithms, clamped to `±Ctrnn::LOG_LIMIT` before decoding, so
+/// every chromosome decodes to a valid network with finite, non-zero time constants and gains.
+#[derive(Clone, Debug)]
+pub struct Ctrnn {
+    topology: CtrnnTopology,
+    weights: Vec<f32>,
+}
+
+impl Ctrnn {
+    /// Bound on the logarithms of time constants and gains; `e^10` is roughly `2.2e4`.
+    pub const LOG_LIMIT: f32 = 10.0;
+
+    pub fn random(rng: &mut dyn RngCore, topology: CtrnnTopology) -> Self {
+        let weights = (0..topology.weights_len())
+            .map(|_| rng.gen_range(-1.0..=1.0))
+            .collect();
+
+        Self { topology, weights }
+    }
+
+    pub fn from_weights(topology: CtrnnTopology, weights: impl IntoIterator<Item = f32>) -> Self {
+        Self::try_from_weights(topology, weights).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_from_weights(topology: CtrnnTopology, weights: impl IntoIterator<Item = f32>) -> Result<Self, NnError> {
+        let expected = topology.weights_len();
+        let weights: Vec<f32> = weights.into_iter().collect();
+
+        if weights.len() < expected {
+            return Err(NnError::NotEnoughWeights { expected, actual: weights.len() });
+        }
+
+        if weights.len() > expected {
+            return Err(NnError::TooManyWeights { expected, actual: weights.len() });
+        }
+
+        Ok(Self { topology, weights })
+    }
+
+    pub fn weights(&self) -> impl Iterator<Item = f32> + '_ {
+        self.weights.iter().copied()
+    }
+
+    pub fn topology(&self) -> CtrnnTopology {
+        self.topology
+    }
+
+    pub fn state(&self) -> State {
+        State { layers: vec![vec![0.0; self.topology.neurons]] }
+    }
+
+    /// Advances the neurons by one Euler step and returns the output neurons' firing rates.
+    pub fn propogate_stateful<'a>(&self, inputs: &[f32], state: &mut State, scratch: &'a mut Scratch) -> &'a [f32] {
+        self.try_propogate_stateful(inputs, state, scratch).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_propogate_stateful<'a>(
+        &self,
+        inputs: &[f32],
+        state: &mut State,
+        scratch: &'a mut Scratch,
+    ) -> Result<&'a [f32], NnError> {
+        if inputs.len() != self.topology.inputs {
+            return Err(NnError::InputLength { expected: self.topology.inputs, actual: inputs.len() });
+        }
+
+        if state.layers.len() != 1 || state.layers[0].len() != self.topology.neurons {
+            return Err(NnError::StateMismatch);
+        }
+
+        let Scratch { outputs, gates: rates, .. } = scratch;
+        let potentials = &mut state.layers[0];
+        let step_size = self.topology.step_size;
+
+        rates.clear();
+        rates.extend(self.rows().zip(potentials.iter()).map(|(row, &potential)| Self::rate(row, potential)));
+
+        for (row, potential) in self.rows().zip(potentials.iter_mut()) {
+            let time_constant = decode_log(row[0]).max(step_size);
+            let (input_weights, recurrent_weights) = row[3..].split_at(inputs.len());
+
+            let input = inputs
+                .iter()
+                .zip(input_weights)
+                .chain(rates.iter().zip(recurrent_weights))
+                .map(|(input, weight)| input * weight)
+                .sum::<f32>();
+
+            *potential += step_size / time_constant * (input - *potential);
+        }
+
+        outputs.clear();
+        outputs.extend(
+            self.rows()
+                .zip(potentials.iter())
+                .skip(self.topology.neurons - self.topology.outputs)
+                .map(|(row, &potential)| Self::rate(row, potential)),
+        );
+
+        Ok(outputs)
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[f32]> {
+        self.weights.chunks_exact(self.topology.row_len())
+    }
+
+    fn rate(row: &[f32], potential: f32) -> f32 {
+        Activation::Sigmoid.apply(decode_log(row[2]) * (potential + row[1]))
+    }
+}
+
+fn decode_log(gene: f32) -> f32 {
+    gene.clamp(-Ctrnn::LOG_LIMIT, Ctrnn::LOG_LIMIT).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn weights() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let topology = CtrnnTopology::new(2, 3, 1);
+        let ctrnn = Ctrnn::random(&mut rng, topology);
+        let weights: Vec<_> = ctrnn.weights().collect();
+
+        assert_eq!(weights.len(), 3 * (3 + 2 + 3));
+        assert_eq!(Ctrnn::from_weights(topology, weights.clone()).weights().collect::<Vec<_>>(), weights);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_topology() {
+        let topology = CtrnnTopology::new(2, 3, 1).with_step_size(0.25);
+        let json = serde_json::to_string(&topology).unwrap();
+
+        assert_eq!(serde_json::from_str::<CtrnnTopology>(&json).unwrap(), topology);
+        assert_eq!((topology.inputs(), topology.neurons(), topology.outputs(), topology.step_size()), (2, 3, 1, 0.25));
+        assert!(serde_json::from_str::<CtrnnTopology>(r#"{"inputs":1,"neurons":1,"outputs":2,"step_size":0.1}"#).is_err());
+        assert!(serde_json::from_str::<CtrnnTopology>(r#"{"inputs":1,"neurons":1,"outputs":1,"step_size":0.0}"#).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "got not enough weights")]
+    fn not_enough_weights() {
+        Ctrnn::from_weights(CtrnnTopology::new(1, 1, 1), vec![0.0; 4]);
+    }
+
+    #[test]
+    fn propogate_stateful() {
+        // A single neuron with time constant e^1, bias 0.5, gain 1, input weight 2 and self-connection -1.
+        let topology = CtrnnTopology::new(1, 1, 1).with_step_size(0.5);
+        let ctrnn = Ctrnn::from_weights(topology, vec![1.0, 0.5, 0.0, 2.0, -1.0]);
+        let mut state = ctrnn.state();
+        let mut scratch = Scratch::default();
+
+        let sigmoid = |x: f32| 1.0 / (1.0 + (-x).exp());
+        let mut potential = 0.0;
+
+        for _ in 0..3 {
+            let input = 2.0 * 1.0 - sigmoid(potential + 0.5);
+            potential += 0.5 / 1.0_f32.exp() * (input - potential);
+
+            let outputs = ctrnn.propogate_stateful(&[1.0], &mut state, &mut scratch);
+
+            assert_relative_eq!(outputs, [sigmoid(potential + 0.5)].as_ref());
+        }
+
+        state.reset();
+
+        assert_eq!(state, ctrnn.state());
+    }
+
+    #[test]
+    fn extreme_genes_stay_finite() {
+        let topology = CtrnnTopology::new(1, 2, 1);
+        let ctrnn = Ctrnn::from_weights(topology, [1e4, 0.0, 1e4, 1.0, -1.0, 1.0, -1e4, 0.5, -1e4, 1.0, 1.0, -1.0]);
+        let mut state = ctrnn.state();
+        let mut scratch = Scratch::default();
+
+        for _ in 0..10 {
+            let outputs = ctrnn.propogate_stateful(&[1.0], &mut state, &mut scratch);
+
+            assert!(outputs.iter().all(|output| output.is_finite()));
+        }
+
+        assert!(state.layers[0].iter().all(|potential| potential.is_finite()));
+    }
+
+    #[test]
+    fn decays_to_rest() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let topology = CtrnnTopology::new(1, 4, 2);
+        let weights: Vec<f32> = Ctrnn::random(&mut rng, topology)
+            .weights()
+            .enumerate()
+            .map(|(i, weight)| if i % 8 < 3 { weight } else { 0.0 })
+            .collect();
+        let ctrnn = Ctrnn::from_weights(topology, weights);
+        let mut state = ctrnn.state();
+        let mut scratch = Scratch::default();
+
+        state.layers[0].fill(5.0);
+
+        for _ in 0..500 {
+            ctrnn.propogate_stateful(&[1.0], &mut state, &mut scratch);
+        }
+
+        assert_relative_eq!(state.layers[0].as_slice(), [0.0; 4].as_ref(), epsilon = 1e-3);
+    }
+}
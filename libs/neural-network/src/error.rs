@@ -0,0 +1,70 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NnError {
+    TooFewLayers { actual: usize },
+    EmptyLayer { layer: usize },
+    NotEnoughWeights { expected: usize, actual: usize },
+    TooManyWeights { expected: usize, actual: usize },
+    InputLength { expected: usize, actual: usize },
+    TargetLength { expected: usize, actual: usize },
+    ParameterCount { expected: usize, actual: usize },
+    BatchLength { input_size: usize, actual: usize },
+    StateMismatch,
+    UnsupportedVersion { expected: u32, actual: u32 },
+    DtypeMismatch { expected: &'static str, actual: String },
+    LayerMismatch { layer: usize, expected: usize, actual: usize },
+    RecurrentLayer { layer: usize },
+    TopologyMismatch,
+    ActivationMismatch,
+    InvalidInitializer,
+    UnknownNode { id: usize },
+    CyclicGenome,
+    InvalidConfig(&'static str),
+}
+
+impl fmt::Display for NnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewLayers { actual } => write!(f, "expected at least 2 layers, got {}", actual),
+            Self::EmptyLayer { layer } => write!(f, "layer {} has no neurons", layer),
+            Self::NotEnoughWeights { expected, actual } => {
+                write!(f, "got not enough weights: expected {}, got {}", expected, actual)
+            }
+            Self::TooManyWeights { expected, actual } => {
+                write!(f, "got too many weights: expected {}, got {}", expected, actual)
+            }
+            Self::InputLength { expected, actual } => write!(f, "expected {} inputs, got {}", expected, actual),
+            Self::TargetLength { expected, actual } => write!(f, "expected {} targets, got {}", expected, actual),
+            Self::ParameterCount { expected, actual } => {
+                write!(f, "got {} gradients for {} weights", actual, expected)
+            }
+            Self::BatchLength { input_size, actual } => {
+                write!(f, "expected a multiple of {} inputs, got {}", input_size, actual)
+            }
+            Self::StateMismatch => write!(f, "state does not belong to this network"),
+            Self::UnsupportedVersion { expected, actual } => {
+                write!(f, "unsupported model version {}, expected {}", actual, expected)
+            }
+            Self::DtypeMismatch { expected, actual } => {
+                write!(f, "model holds {} weights, expected {}", actual, expected)
+            }
+            Self::LayerMismatch { layer, expected, actual } => {
+                write!(f, "layer {} takes {} inputs, but the layer before it has {} neurons", layer, actual, expected)
+            }
+            Self::RecurrentLayer { layer } => {
+                write!(f, "layer {} is recurrent; quantization and backpropagation support only dense layers", layer)
+            }
+            Self::TopologyMismatch => write!(f, "networks have different topologies"),
+            Self::ActivationMismatch => write!(f, "activations don't match the network's layers"),
+            Self::UnknownNode { id } => write!(f, "unknown node {}", id),
+            Self::CyclicGenome => write!(f, "connections form a cycle"),
+            Self::InvalidConfig(reason) => write!(f, "invalid configuration: {}", reason),
+            Self::InvalidInitializer => {
+                write!(f, "uniform limits and normal standard deviations must be finite and non-negative")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NnError {}
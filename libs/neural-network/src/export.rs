@@ -0,0 +1,273 @@
+use crate::*;
+use std::fmt::Write;
+
+const POSITIVE: (u8, u8, u8) = (41, 128, 185);
+const NEGATIVE: (u8, u8, u8) = (192, 57, 43);
+
+const LAYER_SPACING: f32 = 160.0;
+const NEURON_SPACING: f32 = 40.0;
+const NEURON_RADIUS: f32 = 12.0;
+const MARGIN: f32 = 30.0;
+
+impl<F: Float> Network<F> {
+    /// Renders the network as a Graphviz DOT graph, colouring edges by sign and scaling their
+    /// width by magnitude. `activations`, as returned by `propogate_traced`, shade the neurons.
+    ///
+    /// Only the input weights are drawn; for GRU and LSTM layers those of the first gate.
+    pub fn to_dot(&self, activations: Option<&[Vec<F>]>) -> String {
+        self.try_to_dot(activations).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_to_dot(&self, activations: Option<&[Vec<F>]>) -> Result<String, NnError> {
+        self.check_activations(activations)?;
+
+        let sizes = self.layer_sizes();
+        let max_weight = self.max_weight();
+        let mut dot = String::from("digraph network {\n    rankdir=LR;\n    splines=line;\n    node [shape=circle, style=filled, fillcolor=white, fixedsize=true, width=0.4, fontsize=8];\n");
+
+        for (l, &size) in sizes.iter().enumerate() {
+            writeln!(dot, "    subgraph cluster_{} {{\n        style=invis;", l).unwrap();
+
+            for n in 0..size {
+                let activation = activations.map(|activations| activations[l][n].to_f32());
+                let label = activation.map(|value| format!("{:.2}", value)).unwrap_or_default();
+                let fill = activation.map(fill_color).unwrap_or_else(|| "#ffffff".into());
+
+                writeln!(dot, "        n{}_{} [label=\"{}\", fillcolor=\"{}\"];", l, n, label, fill).unwrap();
+            }
+
+            dot.push_str("    }\n");
+        }
+
+        for (l, layer) in self.layers().enumerate() {
+            for (n, neuron) in layer.iter().enumerate() {
+
+                for (i, weight) in neuron.weights[..layer.input_size()].iter().map(|weight| weight.to_f32()).enumerate() {
+                    writeln!(
+                        dot,
+                        "    n{}_{} -> n{}_{} [color=\"{}\", penwidth={:.2}, tooltip=\"{:.3}\"];",
+                        l, i, l + 1, n, edge_color(weight), edge_width(weight, max_weight), weight,
+                    )
+                    .unwrap();
+                }
+
+                if layer.kind() == LayerKind::Elman {
+                    for (i, weight) in neuron.weights[layer.input_size()..].iter().map(|weight| weight.to_f32()).enumerate() {
+                        writeln!(
+                            dot,
+                            "    n{}_{} -> n{}_{} [color=\"{}\", penwidth={:.2}, style=dashed, constraint=false];",
+                            l + 1, i, l + 1, n, edge_color(weight), edge_width(weight, max_weight),
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        Ok(dot)
+    }
+
+    /// Renders the same picture as `to_dot` directly to a standalone SVG document, with
+    /// Elman feedback drawn as dashed curves looping out to the right of their layer.
+    pub fn to_svg(&self, activations: Option<&[Vec<F>]>) -> String {
+        self.try_to_svg(activations).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_to_svg(&self, activations: Option<&[Vec<F>]>) -> Result<String, NnError> {
+        self.check_activations(activations)?;
+
+        let sizes = self.layer_sizes();
+        let max_weight = self.max_weight();
+        let tallest = sizes.iter().copied().max().unwrap_or(0) as f32;
+
+        let width = 2.0 * MARGIN + (sizes.len() - 1) as f32 * LAYER_SPACING;
+        let height = 2.0 * MARGIN + (tallest - 1.0).max(0.0) * NEURON_SPACING;
+
+        let position = |layer: usize, neuron: usize| {
+            let offset = (tallest - sizes[layer] as f32) * NEURON_SPACING / 2.0;
+            (MARGIN + layer as f32 * LAYER_SPACING, MARGIN + offset + neuron as f32 * NEURON_SPACING)
+        };
+
+        let mut svg = String::new();
+
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = width,
+            h = height,
+        )
+        .unwrap();
+
+        for (l, layer) in self.layers().enumerate() {
+            for (n, neuron) in layer.iter().enumerate() {
+                let (x2, y2) = position(l + 1, n);
+
+                for (i, weight) in neuron.weights[..layer.input_size()].iter().map(|weight| weight.to_f32()).enumerate() {
+                    let (x1, y1) = position(l, i);
+
+                    writeln!(
+                        svg,
+                        r#"  <line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="{:.2}"/>"#,
+                        x1, y1, x2, y2, edge_color(weight), edge_width(weight, max_weight),
+                    )
+                    .unwrap();
+                }
+
+                if layer.kind() == LayerKind::Elman {
+                    for (i, weight) in neuron.weights[layer.input_size()..].iter().map(|weight| weight.to_f32()).enumerate() {
+                        let (_, y1) = position(l + 1, i);
+                        let bulge = 2.0 * NEURON_RADIUS + (y2 - y1).abs() / 4.0;
+
+                        writeln!(
+                            svg,
+                            r#"  <path d="M {x:.1} {:.1} C {cx:.1} {:.1} {cx:.1} {:.1} {x:.1} {:.1}" fill="none" stroke="{}" stroke-width="{:.2}" stroke-dasharray="4 2"/>"#,
+                            y1, y1 - NEURON_RADIUS, y2 + NEURON_RADIUS, y2, edge_color(weight), edge_width(weight, max_weight),
+                            x = x2,
+                            cx = x2 + bulge,
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+        }
+
+        for (l, &size) in sizes.iter().enumerate() {
+            for n in 0..size {
+                let (x, y) = position(l, n);
+                let activation = activations.map(|activations| activations[l][n].to_f32());
+                let fill = activation.map(fill_color).unwrap_or_else(|| "#ffffff".into());
+
+                writeln!(
+                    svg,
+                    r#"  <circle cx="{:.1}" cy="{:.1}" r="{}" fill="{}" stroke="black"/>"#,
+                    x, y, NEURON_RADIUS, fill,
+                )
+                .unwrap();
+
+                if let Some(value) = activation {
+                    writeln!(
+                        svg,
+                        r#"  <text x="{:.1}" y="{:.1}" font-size="8" text-anchor="middle" dominant-baseline="central">{:.2}</text>"#,
+                        x, y, value,
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        Ok(svg)
+    }
+
+    fn check_activations(&self, activations: Option<&[Vec<F>]>) -> Result<(), NnError> {
+        if let Some(activations) = activations {
+            let sizes: Vec<_> = activations.iter().map(Vec::len).collect();
+
+            if sizes != self.layer_sizes() {
+                return Err(NnError::ActivationMismatch);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn max_weight(&self) -> f32 {
+        self.weights().map(|weight| weight.to_f32().abs()).fold(0.0, f32::max)
+    }
+}
+
+fn edge_color(weight: f32) -> String {
+    let (r, g, b) = if weight >= 0.0 { POSITIVE } else { NEGATIVE };
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn edge_width(weight: f32, max_weight: f32) -> f32 {
+    if max_weight > 0.0 {
+        0.25 + 2.75 * weight.abs() / max_weight
+    } else {
+        0.25
+    }
+}
+
+/// Blends white towards the sign colour by the activation's magnitude, saturating at 1.
+fn fill_color(activation: f32) -> String {
+    let (r, g, b) = if activation >= 0.0 { POSITIVE } else { NEGATIVE };
+    let t = activation.abs().min(1.0);
+    let blend = |channel: u8| (255.0 + (channel as f32 - 255.0) * t).round() as u8;
+
+    format!("#{:02x}{:02x}{:02x}", blend(r), blend(g), blend(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network() -> Network {
+        Network::from_weights(
+            &[LayerTopology::new(2), LayerTopology::new(1).with_activation(Activation::Identity)],
+            vec![0.5, 1.0, -0.5],
+        )
+    }
+
+    #[test]
+    fn dot() {
+        let network = network();
+        let trace = network.propogate_traced(vec![1.0, 0.0]);
+
+        assert_eq!(
+            network.to_dot(Some(&trace)),
+            "digraph network {
+    rankdir=LR;
+    splines=line;
+    node [shape=circle, style=filled, fillcolor=white, fixedsize=true, width=0.4, fontsize=8];
+    subgraph cluster_0 {
+        style=invis;
+        n0_0 [label=\"1.00\", fillcolor=\"#2980b9\"];
+        n0_1 [label=\"0.00\", fillcolor=\"#ffffff\"];
+    }
+    subgraph cluster_1 {
+        style=invis;
+        n1_0 [label=\"1.50\", fillcolor=\"#2980b9\"];
+    }
+    n0_0 -> n1_0 [color=\"#2980b9\", penwidth=3.00, tooltip=\"1.000\"];
+    n0_1 -> n1_0 [color=\"#c0392b\", penwidth=1.62, tooltip=\"-0.500\"];
+}
+",
+        );
+    }
+
+    #[test]
+    fn svg() {
+        let svg = network().to_svg(None);
+
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="220" height="100" viewBox="0 0 220 100">"#));
+        assert_eq!(svg.matches("<circle").count(), 3);
+        assert_eq!(svg.matches("<line").count(), 2);
+        assert!(svg.contains(r##"<line x1="30.0" y1="70.0" x2="190.0" y2="50.0" stroke="#c0392b" stroke-width="1.62"/>"##));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn svg_elman() {
+        let network = Network::from_weights(
+            &[LayerTopology::new(1), LayerTopology::new(2).with_kind(LayerKind::Elman)],
+            vec![0.0, 1.0, 0.5, -0.5, 0.0, 1.0, 0.25, 0.75],
+        );
+        let svg = network.to_svg(None);
+
+        assert_eq!(svg.matches("<line").count(), 2);
+        assert_eq!(svg.matches("<path").count(), 4);
+        assert_eq!(network.to_dot(None).matches("style=dashed").count(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "activations don't match the network's layers")]
+    fn mismatched_activations() {
+        let network = network();
+
+        assert_eq!(network.try_to_dot(Some(&[vec![1.0]])).err(), Some(NnError::ActivationMismatch));
+
+        network.to_svg(Some(&[vec![1.0]]));
+    }
+}
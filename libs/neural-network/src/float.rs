@@ -0,0 +1,114 @@
+use rand::{Rng, RngCore};
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+/// Floating-point type a `Network` computes in.
+pub trait Float:
+    Copy
+    + Debug
+    + Default
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + Sum
+    + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// Name stored alongside saved models, so they can't be loaded as the wrong type.
+    const NAME: &'static str;
+
+    fn from_f32(value: f32) -> Self;
+    fn to_f32(self) -> f32;
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    fn abs(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cos(self) -> Self;
+    fn tanh(self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+
+    /// Uniform in `[0, 1)`, drawn at this type's precision.
+    fn sample(rng: &mut dyn RngCore) -> Self;
+    /// Uniform in `[-limit, limit]`, drawn at this type's precision.
+    fn sample_symmetric(rng: &mut dyn RngCore, limit: Self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {
+        $(
+            impl Float for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                const NAME: &'static str = stringify!($t);
+
+                fn from_f32(value: f32) -> Self {
+                    value as $t
+                }
+
+                fn to_f32(self) -> f32 {
+                    self as f32
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn exp(self) -> Self {
+                    <$t>::exp(self)
+                }
+
+                fn ln(self) -> Self {
+                    <$t>::ln(self)
+                }
+
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                fn cos(self) -> Self {
+                    <$t>::cos(self)
+                }
+
+                fn tanh(self) -> Self {
+                    <$t>::tanh(self)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    <$t>::max(self, other)
+                }
+
+                fn powi(self, n: i32) -> Self {
+                    <$t>::powi(self, n)
+                }
+
+                fn sample(rng: &mut dyn RngCore) -> Self {
+                    rng.gen()
+                }
+
+                fn sample_symmetric(rng: &mut dyn RngCore, limit: Self) -> Self {
+                    rng.gen_range(-limit..=limit)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
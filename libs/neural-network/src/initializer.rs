@@ -0,0 +1,150 @@
+use crate::*;
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Initializer {
+    /// Uniform in `[-limit, limit]`.
+    Uniform(f32),
+    /// Normal with mean zero and the given standard deviation.
+    Normal(f32),
+    /// Glorot/Xavier uniform, with limit `sqrt(6 / (fan_in + fan_out))`.
+    Xavier,
+    /// He normal, with standard deviation `sqrt(2 / fan_in)`; suited to ReLU layers.
+    He,
+    /// LeCun normal, with standard deviation `sqrt(1 / fan_in)`.
+    LeCun,
+    Zeros,
+}
+
+impl Initializer {
+    pub fn validate(self) -> Result<(), NnError> {
+        match self {
+            Self::Uniform(scale) | Self::Normal(scale) if !scale.is_finite() || scale < 0.0 => {
+                Err(NnError::InvalidInitializer)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Draws a weight at `F`'s precision, so `f64` networks get full `f64` samples.
+    pub fn sample<F: Float>(self, rng: &mut dyn RngCore, fan_in: usize, fan_out: usize) -> F {
+        match self {
+            Self::Uniform(limit) => F::sample_symmetric(rng, F::from_f32(limit)),
+            Self::Normal(std_dev) => F::from_f32(std_dev) * standard_normal(rng),
+            Self::Xavier => {
+                let limit = (F::from_f32(6.0) / fan(fan_in + fan_out)).sqrt();
+                F::sample_symmetric(rng, limit)
+            }
+            Self::He => (F::from_f32(2.0) / fan(fan_in)).sqrt() * standard_normal(rng),
+            Self::LeCun => (F::ONE / fan(fan_in)).sqrt() * standard_normal(rng),
+            Self::Zeros => F::ZERO,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitOptions {
+    pub weights: Initializer,
+    pub biases: Initializer,
+}
+
+impl InitOptions {
+    pub fn new(weights: Initializer, biases: Initializer) -> Self {
+        let options = Self { weights, biases };
+
+        options.validate().unwrap_or_else(|err| panic!("{}", err));
+        options
+    }
+
+    /// The fields are public, so `Network::try_random_with` checks again before sampling.
+    pub fn validate(&self) -> Result<(), NnError> {
+        self.weights.validate()?;
+        self.biases.validate()
+    }
+}
+
+impl Default for InitOptions {
+    fn default() -> Self {
+        Self::new(Initializer::Uniform(1.0), Initializer::Uniform(1.0))
+    }
+}
+
+/// Box-Muller transform, since `rand` alone doesn't ship a normal distribution.
+fn standard_normal<F: Float>(rng: &mut dyn RngCore) -> F {
+    let u1 = F::ONE - F::sample(rng);
+    let u2 = F::sample(rng);
+
+    (F::from_f32(-2.0) * u1.ln()).sqrt() * (F::from_f32(2.0) * F::from_f64(PI) * u2).cos()
+}
+
+fn fan<F: Float>(size: usize) -> F {
+    F::from_f64(size.max(1) as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use approx::assert_relative_eq;
+
+    fn moments(initializer: Initializer, fan_in: usize, fan_out: usize) -> (f32, f32) {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let samples: Vec<_> = (0..20_000).map(|_| initializer.sample(&mut rng, fan_in, fan_out)).collect();
+
+        let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / samples.len() as f32;
+
+        (mean, variance.sqrt())
+    }
+
+    #[test]
+    fn sample() {
+        let cases = [
+            (Initializer::Uniform(3.0), 3.0 / 3.0_f32.sqrt()),
+            (Initializer::Normal(0.5), 0.5),
+            (Initializer::Xavier, (6.0_f32 / 24.0).sqrt() / 3.0_f32.sqrt()),
+            (Initializer::He, (2.0_f32 / 18.0).sqrt()),
+            (Initializer::LeCun, (1.0_f32 / 18.0).sqrt()),
+            (Initializer::Zeros, 0.0),
+        ];
+
+        for (initializer, expected) in cases {
+            let (mean, std_dev) = moments(initializer, 18, 6);
+
+            assert_relative_eq!(mean, 0.0, epsilon = 0.03);
+            assert_relative_eq!(std_dev, expected, epsilon = 0.02);
+        }
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(Initializer::Uniform(0.0).validate(), Ok(()));
+        assert_eq!(Initializer::Normal(0.5).validate(), Ok(()));
+
+        for initializer in [
+            Initializer::Uniform(-1.0),
+            Initializer::Uniform(f32::NAN),
+            Initializer::Normal(-0.5),
+            Initializer::Normal(f32::INFINITY),
+        ] {
+            assert_eq!(initializer.validate(), Err(NnError::InvalidInitializer));
+        }
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let options = InitOptions { weights: Initializer::Uniform(-1.0), biases: Initializer::Zeros };
+
+        assert_eq!(
+            Network::try_random_with(&mut rng, &[LayerTopology::new(1), LayerTopology::new(1)], options).err(),
+            Some(NnError::InvalidInitializer),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must be finite and non-negative")]
+    fn invalid_options() {
+        InitOptions::new(Initializer::Normal(-1.0), Initializer::Zeros);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct LayerView<'a, F = f32> {
+    layer: &'a Layer<F>,
+}
+
+/// A neuron's bias and incoming weights: one per input, followed by one per neuron of the
+/// layer's previous state for recurrent layers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NeuronView<'a, F = f32> {
+    pub bias: F,
+    pub weights: &'a [F],
+}
+
+impl<'a, F: Float> LayerView<'a, F> {
+    pub fn input_size(&self) -> usize {
+        self.layer.input_size
+    }
+
+    pub fn neurons(&self) -> usize {
+        self.layer.output_size
+    }
+
+    pub fn kind(&self) -> LayerKind {
+        self.layer.kind
+    }
+
+    pub fn activation(&self) -> Activation {
+        self.layer.activation
+    }
+
+    pub fn neuron(&self, neuron: usize) -> Option<NeuronView<'a, F>> {
+        self.gate(0, neuron)
+    }
+
+    /// The weights `neuron` uses for `gate`; see `LayerKind::gates`.
+    pub fn gate(&self, gate: usize, neuron: usize) -> Option<NeuronView<'a, F>> {
+        (gate < self.layer.kind.gates() && neuron < self.layer.output_size).then(|| self.row(gate, neuron))
+    }
+
+    /// Every neuron in order; for recurrent layers, the weights of their first gate.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = NeuronView<'a, F>> + '_ {
+        (0..self.layer.output_size).map(|neuron| self.row(0, neuron))
+    }
+
+    fn row(&self, gate: usize, neuron: usize) -> NeuronView<'a, F> {
+        let row_len = self.layer.kind.row_len(self.layer.input_size, self.layer.output_size);
+        let offset = (gate * self.layer.output_size + neuron) * row_len;
+        let row = &self.layer.weights[offset..offset + row_len];
+
+        NeuronView { bias: row[0], weights: &row[1..] }
+    }
+}
+
+impl<F: Float> Network<F> {
+    /// Neuron counts of every layer, starting with the inputs.
+    pub fn layer_sizes(&self) -> Vec<usize> {
+        self.topology().iter().map(|layer| layer.neurons).collect()
+    }
+
+    /// Every layer except the inputs.
+    pub fn layers(&self) -> impl ExactSizeIterator<Item = LayerView<'_, F>> {
+        self.layers.iter().map(|layer| LayerView { layer })
+    }
+
+    pub fn propogate_traced(&self, inputs: Vec<F>) -> Vec<Vec<F>> {
+        self.try_propogate_traced(inputs).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Activations of every layer, starting with the inputs, from a zeroed state.
+    pub fn try_propogate_traced(&self, inputs: Vec<F>) -> Result<Vec<Vec<F>>, NnError> {
+        let expected = self.layers[0].input_size;
+
+        if inputs.len() != expected {
+            return Err(NnError::InputLength { expected, actual: inputs.len() });
+        }
+
+        let mut activations = vec![inputs];
+
+        for layer in &self.layers {
+            let mut state = vec![F::ZERO; layer.state_size()];
+            let mut outputs = Vec::new();
+
+            layer.step(activations.last().unwrap(), &mut state, &mut Vec::new(), &mut outputs);
+            activations.push(outputs);
+        }
+
+        Ok(activations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn accessors() {
+        let layers = [
+            LayerTopology::new(2),
+            LayerTopology::new(3).with_kind(LayerKind::Gru),
+            LayerTopology::new(1).with_activation(Activation::Tanh),
+        ];
+        let weights: Vec<f32> = (0..58).map(|n| n as f32).collect();
+        let network = Network::from_weights(&layers, weights);
+
+        assert_eq!(network.layer_sizes(), vec![2, 3, 1]);
+        assert_eq!(network.parameter_count(), 58);
+        assert_eq!(Network::parameter_count_for(&layers), Ok(58));
+        assert_eq!(network.layers().len(), 2);
+
+        let gru = network.layers().next().unwrap();
+
+        assert_eq!((gru.input_size(), gru.neurons(), gru.kind()), (2, 3, LayerKind::Gru));
+        assert_eq!(gru.neuron(1), Some(NeuronView { bias: 6.0, weights: &[7.0, 8.0, 9.0, 10.0, 11.0] }));
+        assert_eq!(gru.gate(2, 0).map(|gate| gate.bias), Some(36.0));
+        assert_eq!(gru.gate(3, 0), None);
+        assert_eq!(gru.neuron(3), None);
+        assert_eq!(gru.iter().map(|neuron| neuron.bias).collect::<Vec<_>>(), vec![0.0, 6.0, 12.0]);
+
+        let output = network.layers().last().unwrap();
+
+        assert_eq!(output.activation(), Activation::Tanh);
+        assert_eq!(output.neuron(0), Some(NeuronView { bias: 54.0, weights: &[55.0, 56.0, 57.0] }));
+    }
+
+    #[test]
+    fn propogate_traced() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::from_seed(Default::default());
+        let network = Network::random(&mut rng, &[
+            LayerTopology::new(4),
+            LayerTopology::new(3).with_kind(LayerKind::Lstm).with_activation(Activation::Tanh),
+            LayerTopology::new(2),
+        ]);
+        let inputs = vec![0.5, 1.0, 0.25, 1.0];
+
+        let trace = network.propogate_traced(inputs.clone());
+
+        assert_eq!(trace.iter().map(Vec::len).collect::<Vec<_>>(), network.layer_sizes());
+        assert_eq!(trace[0], inputs);
+        assert_relative_eq!(trace[2].as_slice(), network.propogate(inputs).as_slice());
+        assert_eq!(
+            network.try_propogate_traced(vec![1.0]).err(),
+            Some(NnError::InputLength { expected: 4, actual: 1 }),
+        );
+    }
+}
@@ -1,38 +1,221 @@
 use crate::*;
 
 #[derive(Clone, Debug)]
-pub struct Layer {
-    pub(crate) neurons: Vec<Neuron>,
+pub struct Layer<F = f32> {
+    pub(crate) input_size: usize,
+    pub(crate) output_size: usize,
+    pub(crate) kind: LayerKind,
+    /// Row-major matrix with one row per neuron and gate, grouped by gate. Each row holds the
+    /// bias, the input weights and, for recurrent layers, the weights of the previous state.
+    pub(crate) weights: Vec<F>,
+    pub(crate) activation: Activation,
 }
 
 impl Layer {
-    pub fn random(rng: &mut dyn RngCore, input_size: usize, output_size: usize) -> Self {
-        let neurons = (0..output_size)
-                        .into_iter()
-                        .map(|_| Neuron::random(rng, input_size))
-                        .collect();
-
-        return Self{ neurons };
+    pub fn random(rng: &mut dyn RngCore, input_size: usize, topology: LayerTopology, options: InitOptions) -> Self {
+        Self::random_in(rng, input_size, topology, options)
     }
 
     pub fn from_weights(
         input_size: usize,
-        output_size: usize,
+        topology: LayerTopology,
         weights: &mut dyn Iterator<Item = f32>,
     ) -> Self {
-        let neurons = (0..output_size)
-            .map(|_| Neuron::from_weights(input_size, weights))
+        Self::from_weights_in(input_size, topology, weights)
+    }
+}
+
+impl<F: Float> Layer<F> {
+    pub fn random_in(rng: &mut dyn RngCore, input_size: usize, topology: LayerTopology, options: InitOptions) -> Self {
+        let row_len = topology.kind.row_len(input_size, topology.neurons);
+        let fan_in = row_len - 1;
+
+        let weights = (0..topology.kind.weights_len(input_size, topology.neurons))
+                        .map(|i| {
+                            let initializer = if i % row_len == 0 { options.biases } else { options.weights };
+                            initializer.sample(rng, fan_in, topology.neurons)
+                        })
+                        .collect();
+
+        Self::new(input_size, topology, weights)
+    }
+
+    pub fn from_weights_in(
+        input_size: usize,
+        topology: LayerTopology,
+        weights: &mut dyn Iterator<Item = F>,
+    ) -> Self {
+        let weights = (0..topology.kind.weights_len(input_size, topology.neurons))
+            .map(|_| weights.next().expect("got not enough weights"))
             .collect();
 
-        Self { neurons }
+        Self::new(input_size, topology, weights)
     }
 
-    pub fn propogate(&self, inputs: Vec<f32>) -> Vec<f32> {
-        self.neurons
+    fn new(input_size: usize, topology: LayerTopology, weights: Vec<F>) -> Self {
+        // Propagation chunks inputs by `input_size`, which must not be zero.
+        assert!(input_size > 0 && topology.neurons > 0, "layer needs at least one input and one neuron");
+
+        Self {
+            input_size,
+            output_size: topology.neurons,
+            kind: topology.kind,
+            weights,
+            activation: topology.activation,
+        }
+    }
+
+    pub(crate) fn state_size(&self) -> usize {
+        self.kind.state_size(self.output_size)
+    }
+
+    /// Propagates every input vector from a zeroed state, using `state` and `gates` as scratch space.
+    pub fn propogate_batch(
+        &self,
+        inputs: &[F],
+        state: &mut Vec<F>,
+        gates: &mut Vec<F>,
+        outputs: &mut Vec<F>,
+    ) {
+        assert_eq!(inputs.len() % self.input_size, 0);
+
+        outputs.clear();
+
+        for input in inputs.chunks_exact(self.input_size) {
+            state.clear();
+            state.resize(self.state_size(), F::ZERO);
+
+            self.step(input, state, gates, outputs);
+        }
+    }
+
+    /// Appends the layer's outputs for `inputs` to `outputs`, advancing `state`.
+    pub(crate) fn step(&self, inputs: &[F], state: &mut [F], gates: &mut Vec<F>, outputs: &mut Vec<F>) {
+        assert_eq!(inputs.len(), self.input_size);
+        assert_eq!(state.len(), self.state_size());
+
+        let n = self.output_size;
+        let start = outputs.len();
+        let row_len = self.kind.row_len(self.input_size, n);
+        let rows = self.weights.chunks_exact(row_len);
+
+        match self.kind {
+            LayerKind::Dense => {
+                outputs.extend(self.weighted_sums(inputs).map(|output| self.activation.apply_in(output)));
+            }
+
+            LayerKind::Elman => {
+                outputs.extend(rows.map(|row| self.activation.apply_in(weighted_sum(row, inputs, state))));
+                state.copy_from_slice(&outputs[start..]);
+            }
+
+            LayerKind::Gru => {
+                let row = |gate: usize, neuron: usize| {
+                    let offset = (gate * n + neuron) * row_len;
+                    &self.weights[offset..offset + row_len]
+                };
+
+                gates.clear();
+                gates.extend(
+                    state
+                        .iter()
+                        .enumerate()
+                        .map(|(neuron, &h)| sigmoid(weighted_sum(row(1, neuron), inputs, state)) * h),
+                );
+
+                for (neuron, &h) in state.iter().enumerate() {
+                    let z = sigmoid(weighted_sum(row(0, neuron), inputs, state));
+                    let candidate = self.activation.apply_in(weighted_sum(row(2, neuron), inputs, gates));
+
+                    outputs.push((F::ONE - z) * candidate + z * h);
+                }
+
+                state.copy_from_slice(&outputs[start..]);
+            }
+
+            LayerKind::Lstm => {
+                let (h, c) = state.split_at_mut(n);
+                let gate = |gate: usize, neuron: usize| {
+                    let offset = (gate * n + neuron) * row_len;
+                    weighted_sum(&self.weights[offset..offset + row_len], inputs, h)
+                };
+
+                gates.clear();
+
+                for (neuron, &c) in c.iter().enumerate() {
+                    let input = sigmoid(gate(0, neuron));
+                    let forget = sigmoid(gate(1, neuron));
+                    let output = sigmoid(gate(2, neuron));
+                    let cell = forget * c + input * self.activation.apply_in(gate(3, neuron));
+
+                    gates.push(cell);
+                    outputs.push(output * self.activation.apply_in(cell));
+                }
+
+                c.copy_from_slice(gates);
+                h.copy_from_slice(&outputs[start..]);
+            }
+        }
+    }
+
+    pub(crate) fn weighted_sums<'a>(&'a self, inputs: &'a [F]) -> impl Iterator<Item = F> + 'a {
+        self.weights
+            .chunks_exact(self.input_size + 1)
+            .map(|row| weighted_sum(row, inputs, &[]))
+    }
+
+    /// Accumulates this layer's weight gradients and returns the gradient with respect to its inputs.
+    pub(crate) fn backward(
+        &self,
+        inputs: &[F],
+        weighted_sums: &[F],
+        output_gradients: &[F],
+        gradients: &mut [F],
+    ) -> Vec<F> {
+        let mut input_gradients = vec![F::ZERO; self.input_size];
+
+        let rows = self.weights
+            .chunks_exact(self.input_size + 1)
+            .zip(gradients.chunks_exact_mut(self.input_size + 1))
+            .zip(weighted_sums.iter().zip(output_gradients));
+
+        for ((row, gradient_row), (&weighted_sum, &output_gradient)) in rows {
+            let delta = output_gradient * self.activation.derivative_in(weighted_sum);
+
+            gradient_row[0] += delta;
+
+            for i in 0..self.input_size {
+                gradient_row[i + 1] += delta * inputs[i];
+                input_gradients[i] += delta * row[i + 1];
+            }
+        }
+
+        input_gradients
+    }
+}
+
+fn weighted_sum<F: Float>(row: &[F], inputs: &[F], state: &[F]) -> F {
+    let mut output = inputs
+                    .iter()
+                    .zip(&row[1..])
+                    .map(|(&input, &weight)| input * weight)
+                    .sum::<F>();
+
+    output += row[0];
+
+    if !state.is_empty() {
+        output += state
             .iter()
-            .map(|neuron| neuron.propogate(&inputs))
-            .collect()
+            .zip(&row[1 + inputs.len()..])
+            .map(|(&state, &weight)| state * weight)
+            .sum::<F>();
     }
+
+    output
+}
+
+fn sigmoid<F: Float>(x: F) -> F {
+    Activation::Sigmoid.apply_in(x)
 }
 
 #[cfg(test)]
@@ -42,26 +225,150 @@ mod tests {
     use rand_chacha::ChaCha8Rng;
     use approx::assert_relative_eq;
 
+    fn propogate(layer: &Layer, inputs: &[f32], state: &mut [f32]) -> Vec<f32> {
+        let mut outputs = Vec::new();
+        layer.step(inputs, state, &mut Vec::new(), &mut outputs);
+        outputs
+    }
+
     #[test]
     fn layer_random() {
         let mut rng: ChaCha8Rng = ChaCha8Rng::from_seed(Default::default());
-        let layer = Layer::random(&mut rng, 4, 2);
-        
-        assert_relative_eq!(layer.neurons[1].bias, -0.53516835);
-        assert_relative_eq!(layer.neurons[1].weights.as_slice(),
+        let layer = Layer::random(&mut rng, 4, LayerTopology::new(2), InitOptions::default());
+
+        assert_relative_eq!(layer.weights[0], -0.6255188);
+        assert_relative_eq!(&layer.weights[1..5],
+            [0.67383957, 0.8181262, 0.26284897, 0.5238807].as_ref());
+        assert_relative_eq!(layer.weights[5], -0.53516835);
+        assert_relative_eq!(&layer.weights[6..10],
             [0.069369674, -0.7648182, -0.102499366, -0.48879617].as_ref());
     }
 
+    #[test]
+    fn neuron_random() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let layer = Layer::random(&mut rng, 4, LayerTopology::new(1), InitOptions::default());
+
+        assert_relative_eq!(layer.weights[0], -0.6255188);
+        assert_relative_eq!(&layer.weights[1..],
+            [0.67383957, 0.8181262, 0.26284897, 0.5238807].as_ref());
+    }
+
+    #[test]
+    fn neuron_propogate() {
+        let layer = Layer::from_weights(2, LayerTopology::new(1), &mut [0.5, -0.3, 0.8].into_iter());
+
+        assert_relative_eq!(propogate(&layer, &[-10.0, -10.0], &mut []).as_slice(),
+            [0.0].as_ref(),
+        );
+
+        assert_relative_eq!(propogate(&layer, &[0.5, 1.0], &mut []).as_slice(),
+            [(-0.3 * 0.5) + (0.8 * 1.0) + 0.5].as_ref(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "layer needs at least one input and one neuron")]
+    fn layer_without_inputs() {
+        Layer::from_weights(0, LayerTopology::new(1), &mut [0.5].into_iter());
+    }
+
+    #[test]
+    fn layer_random_with_options() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::from_seed(Default::default());
+        let options = InitOptions::new(Initializer::He, Initializer::Zeros);
+        let layer = Layer::random(&mut rng, 18, LayerTopology::new(36), options);
+
+        for row in layer.weights.chunks_exact(19) {
+            assert_eq!(row[0], 0.0);
+            assert!(row[1..].iter().all(|weight| *weight != 0.0));
+        }
+    }
+
+    #[test]
+    fn layer_random_recurrent() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::from_seed(Default::default());
+
+        for (kind, len) in [(LayerKind::Elman, 14), (LayerKind::Gru, 42), (LayerKind::Lstm, 56)] {
+            let layer = Layer::random(&mut rng, 4, LayerTopology::new(2).with_kind(kind), InitOptions::default());
+
+            assert_eq!(layer.weights.len(), len);
+            assert_eq!(layer.state_size(), if kind == LayerKind::Lstm { 4 } else { 2 });
+        }
+    }
+
     #[test]
     fn layer_propogate() {
         let mut rng: ChaCha8Rng = ChaCha8Rng::from_seed(Default::default());
-        let layer = Layer::random(&mut rng, 4, 2);
+        let layer = Layer::random(&mut rng, 4, LayerTopology::new(2), InitOptions::default());
+        let mut outputs = Vec::new();
+
+        layer.propogate_batch(&[0.5, 1.0, 0.25, 1.0], &mut Vec::new(), &mut Vec::new(), &mut outputs);
 
-        assert_relative_eq!(layer.propogate(vec![0.5, 1.0, 0.25, 1.0]).as_slice(),
+        assert_relative_eq!(outputs.as_slice(),
             [(((0.67383957 * 0.5) + (0.8181262 * 1.0) + (0.26284897 * 0.25) + 
             (0.5238807 * 1.0) + -0.6255188) as f32).max(0.0), 
             (((0.069369674 * 0.5) + (-0.7648182 * 1.0) + (-0.102499366 * 0.25) + 
             (-0.48879617 * 1.0) + -0.53516835) as f32).max(0.0)].as_ref());
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn layer_propogate_batch() {
+        let layer = Layer::from_weights(2, LayerTopology::new(1), &mut [0.5, -0.3, 0.8].into_iter());
+        let mut outputs = vec![42.0];
+
+        layer.propogate_batch(&[-10.0, -10.0, 0.5, 1.0], &mut Vec::new(), &mut Vec::new(), &mut outputs);
+
+        assert_relative_eq!(outputs.as_slice(),
+            [0.0, (-0.3 * 0.5) + (0.8 * 1.0) + 0.5].as_ref());
+    }
+
+    #[test]
+    fn elman() {
+        let topology = LayerTopology::new(1).with_kind(LayerKind::Elman).with_activation(Activation::Identity);
+        let layer = Layer::from_weights(1, topology, &mut [0.1, 1.0, 0.5].into_iter());
+        let mut state = [0.0];
+
+        assert_relative_eq!(propogate(&layer, &[1.0], &mut state).as_slice(), [1.1].as_ref());
+        assert_relative_eq!(propogate(&layer, &[1.0], &mut state).as_slice(), [1.65].as_ref());
+        assert_relative_eq!(state.as_ref(), [1.65].as_ref());
+    }
+
+    #[test]
+    fn gru() {
+        let topology = LayerTopology::new(1).with_kind(LayerKind::Gru).with_activation(Activation::Tanh);
+        let weights = [
+            0.0, 0.0, 0.0, // update gate, z = 0.5
+            0.0, 0.0, 0.0, // reset gate, r = 0.5
+            0.0, 1.0, 2.0, // candidate
+        ];
+        let layer = Layer::from_weights(1, topology, &mut weights.into_iter());
+        let mut state = [0.4];
+
+        let candidate = (1.0_f32 + 2.0 * 0.5 * 0.4).tanh();
+
+        assert_relative_eq!(
+            propogate(&layer, &[1.0], &mut state).as_slice(),
+            [0.5 * candidate + 0.5 * 0.4].as_ref(),
+        );
+        assert_relative_eq!(state[0], 0.5 * candidate + 0.5 * 0.4);
+    }
+
+    #[test]
+    fn lstm() {
+        let topology = LayerTopology::new(1).with_kind(LayerKind::Lstm).with_activation(Activation::Tanh);
+        let weights = [
+            0.0, 0.0, 0.0, // input gate, 0.5
+            0.0, 0.0, 0.0, // forget gate, 0.5
+            0.0, 0.0, 0.0, // output gate, 0.5
+            0.0, 1.0, 1.0, // cell input
+        ];
+        let layer = Layer::from_weights(1, topology, &mut weights.into_iter());
+        let mut state = [0.2, 0.6];
+
+        let cell = 0.5 * 0.6 + 0.5 * (1.0_f32 + 0.2).tanh();
+
+        assert_relative_eq!(propogate(&layer, &[1.0], &mut state).as_slice(), [0.5 * cell.tanh()].as_ref());
+        assert_relative_eq!(state.as_ref(), [0.5 * cell.tanh(), cell].as_ref());
+    }
+}
@@ -1,4 +1,73 @@
-#[derive(Clone, Copy, Debug)]
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LayerTopology {
     pub neurons: usize,
-}
\ No newline at end of file
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub activation: Activation,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub kind: LayerKind,
+}
+
+impl LayerTopology {
+    pub fn new(neurons: usize) -> Self {
+        Self { neurons, activation: Activation::default(), kind: LayerKind::default() }
+    }
+
+    /// Activation applied to this layer's outputs; ignored for the input layer.
+    pub fn with_activation(mut self, activation: Activation) -> Self {
+        self.activation = activation;
+        self
+    }
+
+    /// How this layer is connected to the previous one; ignored for the input layer.
+    pub fn with_kind(mut self, kind: LayerKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LayerKind {
+    #[default]
+    Dense,
+    /// Feeds the layer's previous outputs back in as extra inputs.
+    Elman,
+    /// Gated recurrent unit; the layer's activation is used for the candidate state.
+    Gru,
+    /// Long short-term memory; the layer's activation is used for the cell input and output.
+    Lstm,
+}
+
+impl LayerKind {
+    pub fn is_recurrent(self) -> bool {
+        self != Self::Dense
+    }
+
+    /// Weight rows per neuron: one per gate for GRU and LSTM layers.
+    pub fn gates(self) -> usize {
+        match self {
+            Self::Dense | Self::Elman => 1,
+            Self::Gru => 3,
+            Self::Lstm => 4,
+        }
+    }
+
+    pub(crate) fn state_size(self, neurons: usize) -> usize {
+        match self {
+            Self::Dense => 0,
+            Self::Elman | Self::Gru => neurons,
+            Self::Lstm => 2 * neurons,
+        }
+    }
+
+    pub(crate) fn row_len(self, input_size: usize, neurons: usize) -> usize {
+        1 + input_size + if self.is_recurrent() { neurons } else { 0 }
+    }
+
+    pub(crate) fn weights_len(self, input_size: usize, neurons: usize) -> usize {
+        self.gates() * neurons * self.row_len(input_size, neurons)
+    }
+}
@@ -1,71 +1,322 @@
+mod activation;
+mod backprop;
+mod ctrnn;
+mod error;
+mod export;
+mod float;
+mod initializer;
+mod introspection;
 mod layer;
+mod loss;
+#[cfg(feature = "serde")]
+mod model;
+pub mod neat;
+mod optimizer;
+mod quantized;
+mod trainer;
 mod layer_topology;
-mod neuron;
 
+pub use self::activation::*;
+pub use self::ctrnn::*;
+pub use self::error::*;
+pub use self::float::*;
+pub use self::initializer::*;
+pub use self::introspection::*;
 use self::layer::*;
+pub use self::loss::*;
+#[cfg(feature = "serde")]
+pub use self::model::*;
+pub use self::optimizer::*;
+pub use self::quantized::*;
+pub use self::trainer::*;
 pub use self::layer_topology::*;
-use self::neuron::*;
 use rand::{Rng, RngCore};
-use std::iter::once;
+use std::mem;
 
 #[derive(Debug, Clone)]
-pub struct Network {
-    layers: Vec<Layer>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "Model<F>",
+        try_from = "Model<F>",
+        bound = "F: Float + serde::Serialize + serde::de::DeserializeOwned"
+    )
+)]
+pub struct Network<F = f32> {
+    layers: Vec<Layer<F>>,
+}
+
+/// Reusable buffers for the forward pass, so propagation doesn't allocate once they've grown.
+#[derive(Debug, Clone, Default)]
+pub struct Scratch<F = f32> {
+    inputs: Vec<F>,
+    outputs: Vec<F>,
+    state: Vec<F>,
+    gates: Vec<F>,
+    quantized: Vec<i8>,
+}
+
+/// Hidden state of a network's recurrent layers (or a CTRNN's neurons), carried between
+/// `propogate_stateful` calls.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State<F = f32> {
+    layers: Vec<Vec<F>>,
+}
+
+impl<F: Float> State<F> {
+    pub fn reset(&mut self) {
+        for layer in &mut self.layers {
+            layer.fill(F::ZERO);
+        }
+    }
 }
 
 impl Network {
-    pub fn random(rng: &mut dyn RngCore, layers: &[LayerTopology]) -> Self {
-        assert!(layers.len() > 1);
+    /// Checks `layers` describes a valid network, returning how many weights (and biases) it takes.
+    pub fn parameter_count_for(layers: &[LayerTopology]) -> Result<usize, NnError> {
+        if layers.len() < 2 {
+            return Err(NnError::TooFewLayers { actual: layers.len() });
+        }
 
-        let layers = layers
+        if let Some(layer) = layers.iter().position(|layer| layer.neurons == 0) {
+            return Err(NnError::EmptyLayer { layer });
+        }
+
+        Ok(layers
             .windows(2)
-            .map(|layers| Layer::random(rng, layers[0].neurons, layers[1].neurons))
-            .collect();
+            .map(|layers| layers[1].kind.weights_len(layers[0].neurons, layers[1].neurons))
+            .sum())
+    }
 
-        return Self{ layers }
+    pub fn random(rng: &mut dyn RngCore, layers: &[LayerTopology]) -> Self {
+        Self::random_with(rng, layers, InitOptions::default())
     }
 
-    pub fn propogate(&self, mut inputs: Vec<f32>) -> Vec<f32> {
-        for layer in &self.layers {
-            inputs = layer.propogate(inputs);
-        }
+    pub fn random_with(rng: &mut dyn RngCore, layers: &[LayerTopology], options: InitOptions) -> Self {
+        Self::random_in(rng, layers, options)
+    }
 
-        return inputs;
+    pub fn try_random(rng: &mut dyn RngCore, layers: &[LayerTopology]) -> Result<Self, NnError> {
+        Self::try_random_with(rng, layers, InitOptions::default())
+    }
+
+    pub fn try_random_with(
+        rng: &mut dyn RngCore,
+        layers: &[LayerTopology],
+        options: InitOptions,
+    ) -> Result<Self, NnError> {
+        Self::try_random_in(rng, layers, options)
     }
 
     pub fn from_weights(
         layers: &[LayerTopology],
         weights: impl IntoIterator<Item = f32>,
     ) -> Self {
-        assert!(layers.len() > 1);
+        Self::from_weights_in(layers, weights)
+    }
+
+    pub fn try_from_weights(
+        layers: &[LayerTopology],
+        weights: impl IntoIterator<Item = f32>,
+    ) -> Result<Self, NnError> {
+        Self::try_from_weights_in(layers, weights)
+    }
+}
+
+impl<F: Float> Network<F> {
+    /// `random_with` for any `Float`, e.g. `Network::<f64>::random_in`; the plain constructors
+    /// stay on `f32` so existing calls don't need annotating.
+    pub fn random_in(rng: &mut dyn RngCore, layers: &[LayerTopology], options: InitOptions) -> Self {
+        Self::try_random_in(rng, layers, options).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_random_in(
+        rng: &mut dyn RngCore,
+        layers: &[LayerTopology],
+        options: InitOptions,
+    ) -> Result<Self, NnError> {
+        Network::parameter_count_for(layers)?;
+        options.validate()?;
+
+        let layers = layers
+            .windows(2)
+            .map(|layers| Layer::random_in(rng, layers[0].neurons, layers[1], options))
+            .collect();
+
+        Ok(Self{ layers })
+    }
+
+    pub fn from_weights_in(
+        layers: &[LayerTopology],
+        weights: impl IntoIterator<Item = F>,
+    ) -> Self {
+        Self::try_from_weights_in(layers, weights).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_from_weights_in(
+        layers: &[LayerTopology],
+        weights: impl IntoIterator<Item = F>,
+    ) -> Result<Self, NnError> {
+        let expected = Network::parameter_count_for(layers)?;
+        let weights: Vec<F> = weights.into_iter().collect();
+
+        if weights.len() < expected {
+            return Err(NnError::NotEnoughWeights { expected, actual: weights.len() });
+        }
+
+        if weights.len() > expected {
+            return Err(NnError::TooManyWeights { expected, actual: weights.len() });
+        }
 
         let mut weights = weights.into_iter();
 
         let layers = layers
             .windows(2)
             .map(|layer| {
-                Layer::from_weights(
+                Layer::from_weights_in(
                     layer[0].neurons,
-                    layer[1].neurons,
+                    layer[1],
                     &mut weights,
                 )
             })
             .collect();
 
-        if weights.next().is_some() {
-            panic!("got too many weights");
+        Ok(Self { layers })
+    }
+
+    pub fn topology(&self) -> Vec<LayerTopology> {
+        let input = LayerTopology::new(self.layers[0].input_size);
+
+        let layers = self.layers.iter().map(|layer| LayerTopology {
+            neurons: layer.output_size,
+            activation: layer.activation,
+            kind: layer.kind,
+        });
+
+        std::iter::once(input).chain(layers).collect()
+    }
+
+    pub fn is_recurrent(&self) -> bool {
+        self.layers.iter().any(|layer| layer.kind.is_recurrent())
+    }
+
+    pub fn state(&self) -> State<F> {
+        State {
+            layers: self.layers.iter().map(|layer| vec![F::ZERO; layer.state_size()]).collect(),
+        }
+    }
+
+    /// Propagates from a zeroed state; use `propogate_stateful` to let recurrent layers remember.
+    pub fn propogate(&self, inputs: Vec<F>) -> Vec<F> {
+        self.try_propogate(inputs).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_propogate(&self, inputs: Vec<F>) -> Result<Vec<F>, NnError> {
+        Ok(self.try_propogate_with(&inputs, &mut Scratch::default())?.to_vec())
+    }
+
+    pub fn propogate_with<'a>(&self, inputs: &[F], scratch: &'a mut Scratch<F>) -> &'a [F] {
+        self.try_propogate_with(inputs, scratch).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_propogate_with<'a>(&self, inputs: &[F], scratch: &'a mut Scratch<F>) -> Result<&'a [F], NnError> {
+        self.check_inputs(inputs)?;
+
+        self.try_propogate_batch(inputs, scratch)
+    }
+
+    /// Propagates a row-major batch of input vectors, returning the outputs in the same layout.
+    pub fn propogate_batch<'a>(&self, inputs: &[F], scratch: &'a mut Scratch<F>) -> &'a [F] {
+        self.try_propogate_batch(inputs, scratch).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_propogate_batch<'a>(&self, inputs: &[F], scratch: &'a mut Scratch<F>) -> Result<&'a [F], NnError> {
+        let Scratch { inputs: layer_inputs, outputs, state, gates, .. } = scratch;
+        let (first, rest) = self.layers.split_first().expect("network has no layers");
+
+        if inputs.len() % first.input_size != 0 {
+            return Err(NnError::BatchLength { input_size: first.input_size, actual: inputs.len() });
+        }
+
+        first.propogate_batch(inputs, state, gates, outputs);
+
+        for layer in rest {
+            mem::swap(layer_inputs, outputs);
+            layer.propogate_batch(layer_inputs, state, gates, outputs);
         }
 
-        Self { layers }
+        Ok(outputs)
     }
 
-    pub fn weights(&self) -> impl Iterator<Item = f32> + '_ {
+    pub fn propogate_stateful<'a>(&self, inputs: &[F], state: &mut State<F>, scratch: &'a mut Scratch<F>) -> &'a [F] {
+        self.try_propogate_stateful(inputs, state, scratch).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_propogate_stateful<'a>(
+        &self,
+        inputs: &[F],
+        state: &mut State<F>,
+        scratch: &'a mut Scratch<F>,
+    ) -> Result<&'a [F], NnError> {
+        self.check_inputs(inputs)?;
+
+        let matches = state.layers.len() == self.layers.len()
+            && self.layers.iter().zip(&state.layers).all(|(layer, state)| layer.state_size() == state.len());
+
+        if !matches {
+            return Err(NnError::StateMismatch);
+        }
+
+        let Scratch { inputs: layer_inputs, outputs, gates, .. } = scratch;
+
+        outputs.clear();
+        outputs.extend_from_slice(inputs);
+
+        for (layer, state) in self.layers.iter().zip(&mut state.layers) {
+            mem::swap(layer_inputs, outputs);
+            outputs.clear();
+            layer.step(layer_inputs, state, gates, outputs);
+        }
+
+        Ok(outputs)
+    }
+
+    fn check_inputs(&self, inputs: &[F]) -> Result<(), NnError> {
+        let expected = self.layers[0].input_size;
+
+        if inputs.len() != expected {
+            return Err(NnError::InputLength { expected, actual: inputs.len() });
+        }
+
+        Ok(())
+    }
+
+    pub fn weights(&self) -> impl Iterator<Item = F> + '_ {
         self.layers
             .iter()
-            .flat_map(|layer| layer.neurons.iter())
-            .flat_map(|neuron| once(&neuron.bias).chain(&neuron.weights))
+            .flat_map(|layer| &layer.weights)
             .copied()
     }
+
+    pub(crate) fn weights_mut(&mut self) -> impl Iterator<Item = &mut F> {
+        self.layers
+            .iter_mut()
+            .flat_map(|layer| &mut layer.weights)
+    }
+
+    pub fn parameter_count(&self) -> usize {
+        self.layers.iter().map(|layer| layer.weights.len()).sum()
+    }
+
+    /// Converts the network to another float type, e.g. to replay an `f32` brain in `f64`;
+    /// goes through `f64`, so widening is lossless.
+    pub fn cast<G: Float>(&self) -> Network<G> {
+        let topology = self.topology();
+
+        Network::from_weights_in(&topology, self.weights().map(|weight| G::from_f64(weight.to_f64())))
+    }
 }
 
 
@@ -82,20 +333,21 @@ mod tests {
     fn network_random() {
         let mut rng: ChaCha8Rng = ChaCha8Rng::from_seed(Default::default());
         let network = Network::random(&mut rng,
-             &[LayerTopology{ neurons: 4 }, LayerTopology{ neurons: 2 }, LayerTopology{ neurons: 1 }]);
+             &[LayerTopology::new(4), LayerTopology::new(2), LayerTopology::new(1)]);
 
-        let neuron = &network.layers[1].neurons[0];
+        let neuron = &network.layers[1].weights[0..3];
 
-        assert_relative_eq!(neuron.bias, -0.19277132);
-        assert_relative_eq!(neuron.weights.as_slice(),
+        assert_relative_eq!(neuron[0], -0.19277132);
+        assert_relative_eq!(&neuron[1..],
             [-0.8020501, 0.2754606].as_ref());
     }
 
     #[test]
+    #[allow(clippy::excessive_precision, clippy::unnecessary_cast)]
     fn network_propogate() {
         let mut rng: ChaCha8Rng = ChaCha8Rng::from_seed(Default::default());
         let network = Network::random(&mut rng,
-             &[LayerTopology{ neurons: 4 }, LayerTopology{ neurons: 2 }, LayerTopology{ neurons: 1 }]);
+             &[LayerTopology::new(4), LayerTopology::new(2), LayerTopology::new(1)]);
         
         assert_relative_eq!(network.propogate(vec![0.5, 1.0, 0.25, 1.0]).as_slice(),
             [(((1.1191201275 * -0.8020501) + (0.0 * 0.2754606)) + -0.19277132 as f32).max(0.0)].as_ref())
@@ -104,8 +356,8 @@ mod tests {
     #[test]
     fn from_weights() {
         let layers = &[
-            LayerTopology { neurons: 3 },
-            LayerTopology { neurons: 2 },
+            LayerTopology::new(3),
+            LayerTopology::new(2),
         ];
 
         let weights = vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8];
@@ -120,16 +372,18 @@ mod tests {
         let network = Network {
             layers: vec![
                 Layer {
-                    neurons: vec![Neuron {
-                        bias: 0.1,
-                        weights: vec![0.2, 0.3, 0.4],
-                    }],
+                    input_size: 3,
+                    output_size: 1,
+                    kind: LayerKind::Dense,
+                    weights: vec![0.1, 0.2, 0.3, 0.4],
+                    activation: Activation::ReLU,
                 },
                 Layer {
-                    neurons: vec![Neuron {
-                        bias: 0.5,
-                        weights: vec![0.6, 0.7, 0.8],
-                    }],
+                    input_size: 3,
+                    output_size: 1,
+                    kind: LayerKind::Dense,
+                    weights: vec![0.5, 0.6, 0.7, 0.8],
+                    activation: Activation::ReLU,
                 },
             ],
         };
@@ -140,4 +394,142 @@ mod tests {
         assert_eq!(actual.as_slice(), expected.as_slice());
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn network_propogate_with_activations() {
+        let layers = &[
+            LayerTopology::new(2),
+            LayerTopology::new(2).with_activation(Activation::Identity),
+            LayerTopology::new(1).with_activation(Activation::Tanh),
+        ];
+
+        let network = Network::from_weights(layers, vec![0.0, 1.0, 0.0, -1.0, 0.0, -1.0, 0.0, 0.0, 1.0]);
+
+        assert_relative_eq!(network.propogate(vec![0.5, 2.0]).as_slice(), [(-3.0_f32).tanh()].as_ref());
+    }
+
+    #[test]
+    fn network_propogate_batch() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::from_seed(Default::default());
+        let network = Network::random(&mut rng,
+             &[LayerTopology::new(4), LayerTopology::new(3), LayerTopology::new(2)]);
+        let mut scratch = Scratch::default();
+
+        let inputs = [[0.5, 1.0, 0.25, 1.0], [-1.0, 0.0, 0.75, 0.5], [0.0, 0.0, 0.0, 0.0]];
+        let expected: Vec<f32> = inputs
+            .iter()
+            .flat_map(|input| network.propogate(input.to_vec()))
+            .collect();
+
+        let actual = network.propogate_batch(inputs.as_flattened(), &mut scratch);
+
+        assert_relative_eq!(actual, expected.as_slice());
+        assert_relative_eq!(network.propogate_with(&inputs[1], &mut scratch), &expected[2..4]);
+    }
+
+    #[test]
+    fn network_propogate_stateful() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::from_seed(Default::default());
+        let layers = [
+            LayerTopology::new(3),
+            LayerTopology::new(4).with_kind(LayerKind::Lstm).with_activation(Activation::Tanh),
+            LayerTopology::new(2).with_kind(LayerKind::Gru).with_activation(Activation::Tanh),
+            LayerTopology::new(2).with_kind(LayerKind::Elman).with_activation(Activation::Tanh),
+        ];
+        let network = Network::random(&mut rng, &layers);
+        let mut state = network.state();
+        let mut scratch = Scratch::default();
+        let inputs = [0.5, -1.0, 0.25];
+
+        assert!(network.is_recurrent());
+
+        let first = network.propogate_stateful(&inputs, &mut state, &mut scratch).to_vec();
+        let second = network.propogate_stateful(&inputs, &mut state, &mut scratch).to_vec();
+
+        assert_relative_eq!(first.as_slice(), network.propogate(inputs.to_vec()).as_slice());
+        assert!(first != second);
+
+        state.reset();
+
+        assert_relative_eq!(network.propogate_stateful(&inputs, &mut state, &mut scratch), first.as_slice());
+
+        let weights: Vec<_> = network.weights().collect();
+        let restored = Network::from_weights(&layers, weights.clone());
+
+        assert_eq!(restored.weights().collect::<Vec<_>>(), weights);
+    }
+
+    #[test]
+    fn errors() {
+        let layers = &[LayerTopology::new(2), LayerTopology::new(1)];
+        let mut rng: ChaCha8Rng = ChaCha8Rng::from_seed(Default::default());
+
+        assert_eq!(
+            Network::try_random(&mut rng, &layers[..1]).err(),
+            Some(NnError::TooFewLayers { actual: 1 }),
+        );
+        assert_eq!(
+            Network::try_from_weights(&[LayerTopology::new(2), LayerTopology::new(0)], vec![]).err(),
+            Some(NnError::EmptyLayer { layer: 1 }),
+        );
+        assert_eq!(
+            Network::try_from_weights(layers, vec![0.0; 2]).err(),
+            Some(NnError::NotEnoughWeights { expected: 3, actual: 2 }),
+        );
+        assert_eq!(
+            Network::try_from_weights(layers, vec![0.0; 5]).err(),
+            Some(NnError::TooManyWeights { expected: 3, actual: 5 }),
+        );
+
+        let network = Network::try_from_weights(layers, vec![0.0; 3]).unwrap();
+        let mut scratch = Scratch::default();
+
+        assert_eq!(
+            network.try_propogate(vec![1.0]).err(),
+            Some(NnError::InputLength { expected: 2, actual: 1 }),
+        );
+        assert_eq!(
+            network.try_propogate_batch(&[1.0; 3], &mut scratch).err(),
+            Some(NnError::BatchLength { input_size: 2, actual: 3 }),
+        );
+
+        let other = Network::random(&mut rng, &[LayerTopology::new(2), LayerTopology::new(1).with_kind(LayerKind::Elman)]);
+
+        assert_eq!(
+            network.try_propogate_stateful(&[1.0, 1.0], &mut other.state(), &mut scratch).err(),
+            Some(NnError::StateMismatch),
+        );
+    }
+
+    #[test]
+    fn network_f64() {
+        let layers = [
+            LayerTopology::new(3),
+            LayerTopology::new(4).with_kind(LayerKind::Gru).with_activation(Activation::Tanh),
+            LayerTopology::new(2).with_activation(Activation::Sigmoid),
+        ];
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let wide = Network::<f64>::random_in(&mut rng, &layers, InitOptions::default());
+        let network = wide.cast::<f32>();
+
+        assert!(wide.weights().any(|weight| weight != weight as f32 as f64));
+        assert_eq!(wide.cast::<f64>().weights().collect::<Vec<_>>(), wide.weights().collect::<Vec<_>>());
+        assert_eq!(
+            network.cast::<f64>().cast::<f32>().weights().collect::<Vec<_>>(),
+            network.weights().collect::<Vec<_>>(),
+        );
+
+        let outputs = wide.propogate(vec![0.5, -1.0, 0.25]);
+
+        assert_relative_eq!(
+            outputs.iter().map(|&output| output as f32).collect::<Vec<_>>().as_slice(),
+            network.propogate(vec![0.5, -1.0, 0.25]).as_slice(),
+            epsilon = 1e-6,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "got too many weights: expected 3, got 4")]
+    fn from_weights_with_too_many_weights() {
+        Network::from_weights(&[LayerTopology::new(2), LayerTopology::new(1)], vec![0.0; 4]);
+    }
+}
@@ -0,0 +1,76 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Loss {
+    MeanSquaredError,
+    /// Binary cross-entropy; expects outputs in `(0, 1)`, e.g. from a `Sigmoid` output layer.
+    CrossEntropy,
+}
+
+const EPSILON: f32 = 1e-7;
+
+impl Loss {
+    pub fn loss(self, outputs: &[f32], targets: &[f32]) -> f32 {
+        assert_eq!(outputs.len(), targets.len());
+
+        let sum: f32 = outputs
+            .iter()
+            .zip(targets)
+            .map(|(&output, &target)| match self {
+                Self::MeanSquaredError => (output - target).powi(2),
+                Self::CrossEntropy => {
+                    let output = output.clamp(EPSILON, 1.0 - EPSILON);
+                    -(target * output.ln() + (1.0 - target) * (1.0 - output).ln())
+                }
+            })
+            .sum();
+
+        sum / outputs.len() as f32
+    }
+
+    /// Derivative of the loss with respect to each output.
+    pub fn gradient(self, outputs: &[f32], targets: &[f32]) -> Vec<f32> {
+        assert_eq!(outputs.len(), targets.len());
+
+        let len = outputs.len() as f32;
+
+        outputs
+            .iter()
+            .zip(targets)
+            .map(|(&output, &target)| match self {
+                Self::MeanSquaredError => 2.0 * (output - target) / len,
+                // Beyond the clamp in `loss` the loss is flat, so its derivative is zero there
+                Self::CrossEntropy if !(EPSILON..=1.0 - EPSILON).contains(&output) => 0.0,
+                Self::CrossEntropy => (output - target) / (output * (1.0 - output) * len),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn mean_squared_error() {
+        let loss = Loss::MeanSquaredError;
+
+        assert_relative_eq!(loss.loss(&[1.0, 0.0], &[0.0, 0.0]), 0.5);
+        assert_relative_eq!(loss.gradient(&[1.0, 0.0], &[0.0, 0.0]).as_slice(), [1.0, 0.0].as_ref());
+    }
+
+    #[test]
+    fn cross_entropy() {
+        let loss = Loss::CrossEntropy;
+
+        assert_relative_eq!(loss.loss(&[0.5, 0.9], &[1.0, 1.0]), -(0.5_f32.ln() + 0.9_f32.ln()) / 2.0);
+        assert_relative_eq!(loss.gradient(&[0.5, 0.9], &[1.0, 1.0]).as_slice(), [-1.0, -1.0 / 1.8].as_ref());
+    }
+
+    #[test]
+    fn cross_entropy_clamped() {
+        let loss = Loss::CrossEntropy;
+
+        assert_relative_eq!(loss.loss(&[1.0], &[0.0]), loss.loss(&[1.0 - EPSILON / 2.0], &[0.0]));
+        assert_eq!(loss.gradient(&[0.0, 1.0], &[1.0, 0.0]), vec![0.0, 0.0]);
+    }
+}
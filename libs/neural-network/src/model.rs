@@ -0,0 +1,140 @@
+use crate::*;
+use std::io::{self, Read, Write};
+
+pub const MODEL_VERSION: u32 = 2;
+
+/// On-disk representation of a `Network`; bump `MODEL_VERSION` whenever it changes shape.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct Model<F> {
+    version: u32,
+    /// `Float::NAME` of the weights; version 1 models predate it and always hold `f32`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dtype: Option<String>,
+    topology: Vec<LayerTopology>,
+    weights: Vec<F>,
+}
+
+impl<F: Float> From<Network<F>> for Model<F> {
+    fn from(network: Network<F>) -> Self {
+        Self {
+            version: MODEL_VERSION,
+            dtype: Some(F::NAME.into()),
+            topology: network.topology(),
+            weights: network.weights().collect(),
+        }
+    }
+}
+
+impl<F: Float> TryFrom<Model<F>> for Network<F> {
+    type Error = NnError;
+
+    fn try_from(model: Model<F>) -> Result<Self, Self::Error> {
+        let dtype = match (model.version, model.dtype) {
+            (1, None) => "f32".to_owned(),
+            (MODEL_VERSION, Some(dtype)) => dtype,
+            (version, _) => return Err(NnError::UnsupportedVersion { expected: MODEL_VERSION, actual: version }),
+        };
+
+        if dtype != F::NAME {
+            return Err(NnError::DtypeMismatch { expected: F::NAME, actual: dtype });
+        }
+
+        Network::try_from_weights_in(&model.topology, model.weights)
+    }
+}
+
+impl<F> Network<F>
+where
+    F: Float + serde::Serialize + serde::de::DeserializeOwned,
+{
+    pub fn save(&self, writer: impl Write) -> io::Result<()> {
+        serde_json::to_writer(writer, self)?;
+
+        Ok(())
+    }
+
+    /// Loads a model saved with the same `Float` type; see `Network::load` for `f32`.
+    pub fn load_in(reader: impl Read) -> io::Result<Self> {
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
+
+impl Network {
+    pub fn load(reader: impl Read) -> io::Result<Self> {
+        Self::load_in(reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn save_and_load() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let topology = vec![
+            LayerTopology::new(3),
+            LayerTopology::new(4).with_kind(LayerKind::Gru).with_activation(Activation::Tanh),
+            LayerTopology::new(2).with_activation(Activation::LeakyReLU(0.01)),
+        ];
+        let network = Network::random(&mut rng, &topology);
+
+        let mut file = Vec::new();
+        network.save(&mut file).unwrap();
+
+        let loaded = Network::load(file.as_slice()).unwrap();
+
+        assert_eq!(loaded.topology(), network.topology());
+        assert_eq!(loaded.weights().collect::<Vec<_>>(), network.weights().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn format() {
+        let network = Network::from_weights(&[LayerTopology::new(1), LayerTopology::new(1)], vec![0.5, -1.0]);
+        let json = serde_json::to_string(&network).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"version":2,"dtype":"f32","topology":[{"neurons":1,"activation":"ReLU","kind":"Dense"},{"neurons":1,"activation":"ReLU","kind":"Dense"}],"weights":[0.5,-1.0]}"#,
+        );
+    }
+
+    #[test]
+    fn load_validates_shape() {
+        let load = |json: &str| Network::load(json.as_bytes()).unwrap_err().to_string();
+
+        assert!(load(r#"{"version":3,"dtype":"f32","topology":[],"weights":[]}"#).contains("unsupported model version 3"));
+        assert!(load(r#"{"version":1,"dtype":"f32","topology":[],"weights":[]}"#).contains("unsupported model version 1"));
+        assert!(load(r#"{"version":2,"topology":[],"weights":[]}"#).contains("unsupported model version 2"));
+        assert!(load(r#"{"version":2,"dtype":"f64","topology":[],"weights":[]}"#).contains("model holds f64 weights, expected f32"));
+        assert!(load(r#"{"version":2,"dtype":"f32","topology":[{"neurons":1}],"weights":[]}"#).contains("expected at least 2 layers"));
+        assert!(load(r#"{"version":2,"dtype":"f32","topology":[{"neurons":0},{"neurons":1}],"weights":[0.0]}"#).contains("layer 0 has no neurons"));
+        assert!(load(r#"{"version":2,"dtype":"f32","topology":[{"neurons":2},{"neurons":1}],"weights":[0.0]}"#).contains("got not enough weights: expected 3, got 1"));
+    }
+
+    #[test]
+    fn load_version_1() {
+        let json = r#"{"version":1,"topology":[{"neurons":1,"activation":"ReLU","kind":"Dense"},{"neurons":1,"activation":"ReLU","kind":"Dense"}],"weights":[0.5,-1.0]}"#;
+        let network = Network::load(json.as_bytes()).unwrap();
+
+        assert_eq!(network.weights().collect::<Vec<_>>(), vec![0.5, -1.0]);
+        assert!(Network::<f64>::load_in(json.as_bytes()).unwrap_err().to_string().contains("model holds f32 weights, expected f64"));
+    }
+
+    #[test]
+    fn save_and_load_f64() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let topology = [LayerTopology::new(3), LayerTopology::new(2)];
+        let network = Network::<f64>::random_in(&mut rng, &topology, InitOptions::default());
+
+        let mut file = Vec::new();
+        network.save(&mut file).unwrap();
+
+        let loaded = Network::<f64>::load_in(file.as_slice()).unwrap();
+
+        assert_eq!(loaded.weights().collect::<Vec<_>>(), network.weights().collect::<Vec<_>>());
+        assert!(Network::load(file.as_slice()).unwrap_err().to_string().contains("model holds f64 weights, expected f32"));
+    }
+}
@@ -0,0 +1,363 @@
+mod genome;
+mod graph_network;
+mod innovations;
+mod species;
+
+pub use self::{genome::*, graph_network::*, innovations::*, species::*};
+use crate::{Activation, NnError};
+use rand::{seq::SliceRandom, Rng, RngCore};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawConfig"))]
+pub struct Config {
+    pub compatibility_threshold: f32,
+    pub excess_coefficient: f32,
+    pub disjoint_coefficient: f32,
+    pub weight_coefficient: f32,
+    pub weight_mutation_rate: f32,
+    pub weight_mutation_strength: f32,
+    pub weight_replace_rate: f32,
+    pub add_connection_rate: f32,
+    pub add_node_rate: f32,
+    pub crossover_rate: f32,
+    /// Fraction of each species, by fitness, allowed to reproduce.
+    pub survival_threshold: f32,
+    pub hidden_activation: Activation,
+    pub output_activation: Activation,
+}
+
+impl Config {
+    /// The fields are public, so `Neat::new` checks again before evolving.
+    pub fn validate(&self) -> Result<(), NnError> {
+        let coefficients = [
+            self.compatibility_threshold,
+            self.excess_coefficient,
+            self.disjoint_coefficient,
+            self.weight_coefficient,
+            self.weight_mutation_strength,
+        ];
+
+        if coefficients.iter().any(|value| !value.is_finite() || *value < 0.0) {
+            return Err(NnError::InvalidConfig("coefficients must be finite and non-negative"));
+        }
+
+        let probabilities = [
+            self.weight_mutation_rate,
+            self.weight_replace_rate,
+            self.add_connection_rate,
+            self.add_node_rate,
+            self.crossover_rate,
+            self.survival_threshold,
+        ];
+
+        if probabilities.iter().any(|value| !(0.0..=1.0).contains(value)) {
+            return Err(NnError::InvalidConfig("rates must lie in [0, 1]"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawConfig {
+    compatibility_threshold: f32,
+    excess_coefficient: f32,
+    disjoint_coefficient: f32,
+    weight_coefficient: f32,
+    weight_mutation_rate: f32,
+    weight_mutation_strength: f32,
+    weight_replace_rate: f32,
+    add_connection_rate: f32,
+    add_node_rate: f32,
+    crossover_rate: f32,
+    survival_threshold: f32,
+    hidden_activation: Activation,
+    output_activation: Activation,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawConfig> for Config {
+    type Error = NnError;
+
+    fn try_from(raw: RawConfig) -> Result<Self, Self::Error> {
+        let config = Self {
+            compatibility_threshold: raw.compatibility_threshold,
+            excess_coefficient: raw.excess_coefficient,
+            disjoint_coefficient: raw.disjoint_coefficient,
+            weight_coefficient: raw.weight_coefficient,
+            weight_mutation_rate: raw.weight_mutation_rate,
+            weight_mutation_strength: raw.weight_mutation_strength,
+            weight_replace_rate: raw.weight_replace_rate,
+            add_connection_rate: raw.add_connection_rate,
+            add_node_rate: raw.add_node_rate,
+            crossover_rate: raw.crossover_rate,
+            survival_threshold: raw.survival_threshold,
+            hidden_activation: raw.hidden_activation,
+            output_activation: raw.output_activation,
+        };
+
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            compatibility_threshold: 3.0,
+            excess_coefficient: 1.0,
+            disjoint_coefficient: 1.0,
+            weight_coefficient: 0.4,
+            weight_mutation_rate: 0.8,
+            weight_mutation_strength: 0.5,
+            weight_replace_rate: 0.1,
+            add_connection_rate: 0.05,
+            add_node_rate: 0.03,
+            crossover_rate: 0.75,
+            survival_threshold: 0.2,
+            hidden_activation: Activation::Tanh,
+            output_activation: Activation::Tanh,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Neat {
+    config: Config,
+    inputs: usize,
+    outputs: usize,
+    innovations: Innovations,
+    species: Vec<Species>,
+    next_species_id: usize,
+}
+
+impl Neat {
+    pub fn new(config: Config, inputs: usize, outputs: usize) -> Self {
+        assert!(inputs > 0);
+        assert!(outputs > 0);
+        config.validate().unwrap_or_else(|err| panic!("{}", err));
+
+        Self {
+            config,
+            inputs,
+            outputs,
+            innovations: Innovations::new(inputs + outputs),
+            species: Vec::new(),
+            next_species_id: 0,
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn species(&self) -> &[Species] {
+        &self.species
+    }
+
+    pub fn initial_population(&mut self, rng: &mut dyn RngCore, size: usize) -> Vec<Genome> {
+        (0..size)
+            .map(|_| {
+                Genome::minimal(rng, self.inputs, self.outputs, self.config.output_activation, &mut self.innovations)
+            })
+            .collect()
+    }
+
+    /// Speciates `population` and breeds the next generation, allocating offspring to each species
+    /// in proportion to its shared fitness.
+    pub fn evolve(&mut self, rng: &mut dyn RngCore, population: &[Genome], fitnesses: &[f32]) -> Vec<Genome> {
+        assert!(!population.is_empty());
+        assert_eq!(population.len(), fitnesses.len());
+        assert!(fitnesses.iter().all(|fitness| fitness.is_finite() && *fitness >= 0.0));
+
+        self.speciate(rng, population);
+
+        let shares: Vec<f32> = self
+            .species
+            .iter()
+            .map(|species| {
+                let sum: f32 = species.members.iter().map(|&i| fitnesses[i]).sum();
+                sum / species.members.len() as f32
+            })
+            .collect();
+
+        let offspring = allocate(&shares, population.len());
+        let mut next = Vec::with_capacity(population.len());
+
+        let groups: Vec<_> = self.species.iter().map(|species| species.members.clone()).collect();
+
+        for (mut members, count) in groups.into_iter().zip(offspring) {
+            if count == 0 {
+                continue;
+            }
+
+            members.sort_by(|&a, &b| fitnesses[b].total_cmp(&fitnesses[a]));
+
+            let survivors = ((members.len() as f32 * self.config.survival_threshold).ceil() as usize).max(1);
+            let parents = &members[..survivors];
+
+            next.push(population[members[0]].clone());
+
+            for _ in 1..count {
+                let parent_a = *parents.choose(rng).unwrap();
+
+                let mut child = if rng.gen_bool(self.config.crossover_rate as f64) {
+                    let parent_b = *parents.choose(rng).unwrap();
+
+                    if fitnesses[parent_a] >= fitnesses[parent_b] {
+                        Genome::crossover(rng, &population[parent_a], &population[parent_b])
+                    } else {
+                        Genome::crossover(rng, &population[parent_b], &population[parent_a])
+                    }
+                } else {
+                    population[parent_a].clone()
+                };
+
+                self.mutate(rng, &mut child);
+                next.push(child);
+            }
+        }
+
+        next
+    }
+
+    pub fn mutate(&mut self, rng: &mut dyn RngCore, genome: &mut Genome) {
+        if rng.gen_bool(self.config.weight_mutation_rate as f64) {
+            genome.mutate_weights(rng, self.config.weight_mutation_strength, self.config.weight_replace_rate);
+        }
+
+        if rng.gen_bool(self.config.add_connection_rate as f64) {
+            genome.add_connection(rng, &mut self.innovations);
+        }
+
+        if rng.gen_bool(self.config.add_node_rate as f64) {
+            genome.add_node(rng, self.config.hidden_activation, &mut self.innovations);
+        }
+    }
+
+    fn speciate(&mut self, rng: &mut dyn RngCore, population: &[Genome]) {
+        for species in &mut self.species {
+            species.members.clear();
+        }
+
+        for (i, genome) in population.iter().enumerate() {
+            let existing = self.species.iter_mut().find(|species| {
+                genome.distance(&species.representative, &self.config) < self.config.compatibility_threshold
+            });
+
+            match existing {
+                Some(species) => species.members.push(i),
+                None => {
+                    self.species.push(Species::new(self.next_species_id, genome.clone(), i));
+                    self.next_species_id += 1;
+                }
+            }
+        }
+
+        self.species.retain(|species| !species.members.is_empty());
+
+        for species in &mut self.species {
+            let representative = *species.members.choose(rng).unwrap();
+            species.representative = population[representative].clone();
+        }
+    }
+}
+
+/// Splits `total` offspring proportionally to `shares`, handing leftovers to the largest remainders.
+fn allocate(shares: &[f32], total: usize) -> Vec<usize> {
+    let sum: f32 = shares.iter().sum();
+
+    let quotas: Vec<f32> = if sum > 0.0 {
+        shares.iter().map(|share| share / sum * total as f32).collect()
+    } else {
+        vec![total as f32 / shares.len() as f32; shares.len()]
+    };
+
+    let mut counts: Vec<usize> = quotas.iter().map(|quota| quota.floor() as usize).collect();
+    let mut remainders: Vec<usize> = (0..shares.len()).collect();
+
+    remainders.sort_by(|&a, &b| (quotas[b] - quotas[b].floor()).total_cmp(&(quotas[a] - quotas[a].floor())));
+
+    let allocated: usize = counts.iter().sum();
+
+    for &i in remainders.iter().cycle().take(total.saturating_sub(allocated)) {
+        counts[i] += 1;
+    }
+
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn allocate_offspring() {
+        assert_eq!(allocate(&[1.0, 1.0, 2.0], 10), vec![3, 2, 5]);
+        assert_eq!(allocate(&[0.0, 0.0], 5), vec![3, 2]);
+        assert_eq!(allocate(&[3.0], 4), vec![4]);
+    }
+
+    #[test]
+    fn validate_config() {
+        assert_eq!(Config::default().validate(), Ok(()));
+
+        let config = Config { crossover_rate: 1.5, ..Config::default() };
+        assert!(config.validate().is_err());
+
+        let config = Config { compatibility_threshold: f32::NAN, ..Config::default() };
+        assert!(config.validate().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates_config() {
+        let json = serde_json::to_string(&Config::default()).unwrap();
+        assert!(serde_json::from_str::<Config>(&json).is_ok());
+
+        let json = json.replace(r#""add_node_rate":0.03"#, r#""add_node_rate":-0.5"#);
+        assert!(serde_json::from_str::<Config>(&json).is_err());
+    }
+
+    fn xor_fitness(genome: &Genome) -> f32 {
+        let network = genome.network();
+
+        let error: f32 = [([0.0, 0.0], 0.0), ([0.0, 1.0], 1.0), ([1.0, 0.0], 1.0), ([1.0, 1.0], 0.0)]
+            .iter()
+            .map(|(inputs, target)| (network.propogate(inputs)[0] - target).powi(2))
+            .sum();
+
+        4.0 - error
+    }
+
+    #[test]
+    fn evolve() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let config = Config {
+            output_activation: Activation::Sigmoid,
+            add_node_rate: 0.1,
+            add_connection_rate: 0.2,
+            ..Config::default()
+        };
+        let mut neat = Neat::new(config, 2, 1);
+        let mut population = neat.initial_population(&mut rng, 100);
+
+        let best = |population: &[Genome]| population.iter().map(xor_fitness).fold(0.0, f32::max);
+        let initial = best(&population);
+
+        for _ in 0..50 {
+            let fitnesses: Vec<_> = population.iter().map(xor_fitness).collect();
+            population = neat.evolve(&mut rng, &population, &fitnesses);
+
+            assert_eq!(population.len(), 100);
+        }
+
+        assert!(!neat.species().is_empty());
+        assert!(population.iter().any(|genome| genome.nodes().iter().any(|node| node.kind == NodeKind::Hidden)));
+        assert!(best(&population) > initial);
+    }
+}
@@ -0,0 +1,424 @@
+use super::*;
+use std::cmp::Ordering;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NodeKind {
+    Input,
+    Hidden,
+    Output,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeGene {
+    pub id: usize,
+    pub kind: NodeKind,
+    pub bias: f32,
+    pub activation: Activation,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConnectionGene {
+    pub innovation: usize,
+    pub from: usize,
+    pub to: usize,
+    pub weight: f32,
+    pub enabled: bool,
+}
+
+/// A NEAT genome; connections are kept sorted by innovation number and never form a cycle.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawGenome"))]
+pub struct Genome {
+    nodes: Vec<NodeGene>,
+    connections: Vec<ConnectionGene>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawGenome {
+    nodes: Vec<NodeGene>,
+    connections: Vec<ConnectionGene>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawGenome> for Genome {
+    type Error = NnError;
+
+    fn try_from(raw: RawGenome) -> Result<Self, Self::Error> {
+        Self::try_from_genes(raw.nodes, raw.connections)
+    }
+}
+
+impl Genome {
+    /// Every input connected directly to every output, with random weights.
+    pub fn minimal(
+        rng: &mut dyn RngCore,
+        inputs: usize,
+        outputs: usize,
+        output_activation: Activation,
+        innovations: &mut Innovations,
+    ) -> Self {
+        let nodes = (0..inputs)
+            .map(|id| NodeGene { id, kind: NodeKind::Input, bias: 0.0, activation: Activation::Identity })
+            .chain((inputs..inputs + outputs).map(|id| NodeGene {
+                id,
+                kind: NodeKind::Output,
+                bias: rng.gen_range(-1.0..=1.0),
+                activation: output_activation,
+            }))
+            .collect();
+
+        let mut connections: Vec<_> = (inputs..inputs + outputs)
+            .flat_map(|to| (0..inputs).map(move |from| (from, to)))
+            .map(|(from, to)| ConnectionGene {
+                innovation: innovations.connection(from, to),
+                from,
+                to,
+                weight: rng.gen_range(-1.0..=1.0),
+                enabled: true,
+            })
+            .collect();
+
+        connections.sort_by_key(|connection| connection.innovation);
+
+        Self { nodes, connections }
+    }
+
+    pub fn from_genes(nodes: Vec<NodeGene>, connections: Vec<ConnectionGene>) -> Self {
+        Self::try_from_genes(nodes, connections).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_from_genes(nodes: Vec<NodeGene>, mut connections: Vec<ConnectionGene>) -> Result<Self, NnError> {
+        connections.sort_by_key(|connection| connection.innovation);
+
+        let genome = Self { nodes, connections };
+
+        for connection in &genome.connections {
+            for id in [connection.from, connection.to] {
+                if !genome.nodes.iter().any(|node| node.id == id) {
+                    return Err(NnError::UnknownNode { id });
+                }
+            }
+
+            if genome.reaches(connection.to, connection.from) {
+                return Err(NnError::CyclicGenome);
+            }
+        }
+
+        Ok(genome)
+    }
+
+    pub fn nodes(&self) -> &[NodeGene] {
+        &self.nodes
+    }
+
+    pub fn connections(&self) -> &[ConnectionGene] {
+        &self.connections
+    }
+
+    pub fn network(&self) -> GraphNetwork {
+        GraphNetwork::new(self)
+    }
+
+    /// Perturbs every weight and bias, replacing some with fresh random values.
+    pub fn mutate_weights(&mut self, rng: &mut dyn RngCore, strength: f32, replace_rate: f32) {
+        let weights = self
+            .connections
+            .iter_mut()
+            .map(|connection| &mut connection.weight)
+            .chain(
+                self.nodes
+                    .iter_mut()
+                    .filter(|node| node.kind != NodeKind::Input)
+                    .map(|node| &mut node.bias),
+            );
+
+        for weight in weights {
+            if rng.gen_bool(replace_rate as f64) {
+                *weight = rng.gen_range(-1.0..=1.0);
+            } else {
+                *weight += rng.gen_range(-strength..=strength);
+            }
+        }
+    }
+
+    /// Connects two previously unconnected nodes, unless doing so would create a cycle.
+    pub fn add_connection(&mut self, rng: &mut dyn RngCore, innovations: &mut Innovations) -> bool {
+        let candidates: Vec<_> = self
+            .nodes
+            .iter()
+            .filter(|from| from.kind != NodeKind::Output)
+            .flat_map(|from| {
+                self.nodes
+                    .iter()
+                    .filter(|to| to.kind != NodeKind::Input && to.id != from.id)
+                    .map(move |to| (from.id, to.id))
+            })
+            .filter(|&(from, to)| {
+                !self.connections.iter().any(|connection| connection.from == from && connection.to == to)
+                    && !self.reaches(to, from)
+            })
+            .collect();
+
+        let Some(&(from, to)) = candidates.choose(rng) else {
+            return false;
+        };
+
+        self.insert_connection(ConnectionGene {
+            innovation: innovations.connection(from, to),
+            from,
+            to,
+            weight: rng.gen_range(-1.0..=1.0),
+            enabled: true,
+        });
+
+        true
+    }
+
+    /// Splits an enabled connection in two, inserting a hidden node that initially passes the
+    /// signal through unchanged.
+    pub fn add_node(&mut self, rng: &mut dyn RngCore, activation: Activation, innovations: &mut Innovations) -> bool {
+        let enabled: Vec<_> = (0..self.connections.len())
+            .filter(|&i| self.connections[i].enabled)
+            .collect();
+
+        let Some(&i) = enabled.choose(rng) else {
+            return false;
+        };
+
+        let ConnectionGene { innovation, from, to, weight, .. } = self.connections[i];
+        let id = innovations.split(innovation);
+
+        if self.nodes.iter().any(|node| node.id == id) {
+            return false;
+        }
+
+        self.connections[i].enabled = false;
+        self.nodes.push(NodeGene { id, kind: NodeKind::Hidden, bias: 0.0, activation });
+
+        for (from, to, weight) in [(from, id, 1.0), (id, to, weight)] {
+            self.insert_connection(ConnectionGene {
+                innovation: innovations.connection(from, to),
+                from,
+                to,
+                weight,
+                enabled: true,
+            });
+        }
+
+        true
+    }
+
+    /// Matching genes are inherited from either parent at random, disjoint and excess genes from
+    /// the fitter one; a gene disabled in either parent stays disabled with 75% probability.
+    pub fn crossover(rng: &mut dyn RngCore, fitter: &Genome, other: &Genome) -> Genome {
+        let nodes = fitter
+            .nodes
+            .iter()
+            .map(|node| match other.nodes.iter().find(|other| other.id == node.id) {
+                Some(other) if rng.gen_bool(0.5) => NodeGene { kind: node.kind, ..other.clone() },
+                _ => node.clone(),
+            })
+            .collect();
+
+        let connections = fitter
+            .connections
+            .iter()
+            .map(|connection| {
+                let matching = other
+                    .connections
+                    .binary_search_by_key(&connection.innovation, |other| other.innovation)
+                    .ok()
+                    .map(|i| &other.connections[i]);
+
+                let Some(matching) = matching else {
+                    return connection.clone();
+                };
+
+                let weight = if rng.gen_bool(0.5) { connection.weight } else { matching.weight };
+                let enabled = (connection.enabled && matching.enabled) || rng.gen_bool(0.25);
+
+                ConnectionGene { weight, enabled, ..connection.clone() }
+            })
+            .collect();
+
+        Genome { nodes, connections }
+    }
+
+    /// NEAT compatibility distance, from excess and disjoint genes and the mean weight difference
+    /// of matching ones.
+    pub fn distance(&self, other: &Genome, config: &Config) -> f32 {
+        let (a, b) = (&self.connections, &other.connections);
+        let (mut i, mut j) = (0, 0);
+        let (mut disjoint, mut matching, mut weight_difference) = (0, 0, 0.0);
+
+        while i < a.len() && j < b.len() {
+            match a[i].innovation.cmp(&b[j].innovation) {
+                Ordering::Equal => {
+                    matching += 1;
+                    weight_difference += (a[i].weight - b[j].weight).abs();
+                    i += 1;
+                    j += 1;
+                }
+                Ordering::Less => {
+                    disjoint += 1;
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    disjoint += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        let excess = (a.len() - i) + (b.len() - j);
+        let genes = a.len().max(b.len()).max(1) as f32;
+        let weight_difference = if matching > 0 { weight_difference / matching as f32 } else { 0.0 };
+
+        config.excess_coefficient * excess as f32 / genes
+            + config.disjoint_coefficient * disjoint as f32 / genes
+            + config.weight_coefficient * weight_difference
+    }
+
+    fn insert_connection(&mut self, connection: ConnectionGene) {
+        let i = self.connections.partition_point(|other| other.innovation < connection.innovation);
+        self.connections.insert(i, connection);
+    }
+
+    fn reaches(&self, from: usize, to: usize) -> bool {
+        let mut pending = vec![from];
+        let mut visited = vec![from];
+
+        while let Some(node) = pending.pop() {
+            if node == to {
+                return true;
+            }
+
+            for connection in self.connections.iter().filter(|connection| connection.from == node) {
+                if !visited.contains(&connection.to) {
+                    visited.push(connection.to);
+                    pending.push(connection.to);
+                }
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use approx::assert_relative_eq;
+
+    fn minimal(rng: &mut dyn RngCore, innovations: &mut Innovations) -> Genome {
+        Genome::minimal(rng, 2, 1, Activation::Identity, innovations)
+    }
+
+    #[test]
+    fn structural_mutations() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut innovations = Innovations::new(3);
+        let mut genome = minimal(&mut rng, &mut innovations);
+
+        assert_eq!(genome.connections().len(), 2);
+
+        let inputs = [0.5, -0.25];
+        let before = genome.network().propogate(&inputs);
+
+        assert!(genome.add_node(&mut rng, Activation::Identity, &mut innovations));
+        assert_eq!(genome.nodes().len(), 4);
+        assert_eq!(genome.connections().iter().filter(|connection| connection.enabled).count(), 3);
+        assert_relative_eq!(genome.network().propogate(&inputs).as_slice(), before.as_slice());
+
+        assert!(genome.add_connection(&mut rng, &mut innovations));
+        assert_eq!(genome.connections().len(), 5);
+
+        for _ in 0..50 {
+            genome.add_node(&mut rng, Activation::Tanh, &mut innovations);
+            genome.add_connection(&mut rng, &mut innovations);
+        }
+
+        assert!(genome.connections().windows(2).all(|pair| pair[0].innovation < pair[1].innovation));
+        assert_eq!(genome.network().propogate(&inputs).len(), 1);
+    }
+
+    #[test]
+    fn try_from_genes() {
+        let node = |id, kind| NodeGene { id, kind, bias: 0.0, activation: Activation::Identity };
+        let connection = |innovation, from, to| ConnectionGene { innovation, from, to, weight: 1.0, enabled: true };
+        let nodes = vec![node(0, NodeKind::Input), node(1, NodeKind::Hidden), node(2, NodeKind::Output)];
+
+        assert!(Genome::try_from_genes(nodes.clone(), vec![connection(0, 0, 1), connection(1, 1, 2)]).is_ok());
+        assert_eq!(
+            Genome::try_from_genes(nodes.clone(), vec![connection(0, 0, 5)]).err(),
+            Some(NnError::UnknownNode { id: 5 }),
+        );
+        assert_eq!(
+            Genome::try_from_genes(nodes, vec![connection(0, 1, 2), connection(1, 2, 1)]).err(),
+            Some(NnError::CyclicGenome),
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let genome = minimal(&mut rng, &mut Innovations::new(3));
+        let json = serde_json::to_string(&genome).unwrap();
+
+        assert_eq!(serde_json::from_str::<Genome>(&json).unwrap(), genome);
+
+        let json = json.replacen(r#""to":2"#, r#""to":7"#, 1);
+
+        assert!(serde_json::from_str::<Genome>(&json).unwrap_err().to_string().starts_with("unknown node 7"));
+    }
+
+    #[test]
+    fn shared_innovations() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut innovations = Innovations::new(3);
+        let mut a = minimal(&mut rng, &mut innovations);
+        let mut b = minimal(&mut rng, &mut innovations);
+
+        // Both genomes have exactly one connection to split after the first one is disabled.
+        a.connections[0].enabled = false;
+        b.connections[0].enabled = false;
+
+        a.add_node(&mut rng, Activation::Tanh, &mut innovations);
+        b.add_node(&mut rng, Activation::Tanh, &mut innovations);
+
+        let innovations = |genome: &Genome| genome.connections().iter().map(|c| c.innovation).collect::<Vec<_>>();
+
+        assert_eq!(innovations(&a), innovations(&b));
+        assert_eq!(a.nodes().last().unwrap().id, b.nodes().last().unwrap().id);
+    }
+
+    #[test]
+    fn crossover_and_distance() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut innovations = Innovations::new(3);
+        let config = Config::default();
+        let fitter = minimal(&mut rng, &mut innovations);
+        let mut other = fitter.clone();
+
+        assert_relative_eq!(fitter.distance(&other, &config), 0.0);
+
+        other.add_node(&mut rng, Activation::Tanh, &mut innovations);
+        other.connections[1].weight += 1.0;
+
+        // Two excess genes out of four, and a mean weight difference of 0.5 over two matching ones.
+        assert_relative_eq!(other.distance(&fitter, &config), 2.0 / 4.0 + 0.4 * 0.5);
+
+        let child = Genome::crossover(&mut rng, &fitter, &other);
+
+        assert_eq!(child.nodes().len(), fitter.nodes().len());
+        assert_eq!(child.connections().len(), fitter.connections().len());
+    }
+}
@@ -0,0 +1,119 @@
+use super::*;
+
+/// Feed-forward evaluator for a `Genome`, with nodes visited in topological order.
+#[derive(Clone, Debug)]
+pub struct GraphNetwork {
+    size: usize,
+    inputs: Vec<usize>,
+    outputs: Vec<usize>,
+    nodes: Vec<GraphNode>,
+}
+
+#[derive(Clone, Debug)]
+struct GraphNode {
+    index: usize,
+    bias: f32,
+    activation: Activation,
+    incoming: Vec<(usize, f32)>,
+}
+
+impl GraphNetwork {
+    pub(super) fn new(genome: &Genome) -> Self {
+        let genes = genome.nodes();
+        let index = |id: usize| genes.iter().position(|node| node.id == id).unwrap();
+        let indices_of = |kind: NodeKind| {
+            let mut nodes: Vec<_> = genes.iter().filter(|node| node.kind == kind).collect();
+            nodes.sort_by_key(|node| node.id);
+            nodes.into_iter().map(|node| index(node.id)).collect::<Vec<_>>()
+        };
+
+        let mut incoming = vec![Vec::new(); genes.len()];
+
+        for connection in genome.connections().iter().filter(|connection| connection.enabled) {
+            incoming[index(connection.to)].push((index(connection.from), connection.weight));
+        }
+
+        let mut pending: Vec<usize> = incoming.iter().map(Vec::len).collect();
+        let mut ready: Vec<usize> = (0..genes.len()).filter(|&i| pending[i] == 0).collect();
+        let mut nodes = Vec::with_capacity(genes.len());
+
+        while let Some(i) = ready.pop() {
+            if genes[i].kind != NodeKind::Input {
+                nodes.push(GraphNode {
+                    index: i,
+                    bias: genes[i].bias,
+                    activation: genes[i].activation,
+                    incoming: incoming[i].clone(),
+                });
+            }
+
+            for (j, sources) in incoming.iter().enumerate() {
+                for _ in sources.iter().filter(|(from, _)| *from == i) {
+                    pending[j] -= 1;
+
+                    if pending[j] == 0 {
+                        ready.push(j);
+                    }
+                }
+            }
+        }
+
+        Self {
+            size: genes.len(),
+            inputs: indices_of(NodeKind::Input),
+            outputs: indices_of(NodeKind::Output),
+            nodes,
+        }
+    }
+
+    pub fn propogate(&self, inputs: &[f32]) -> Vec<f32> {
+        assert_eq!(inputs.len(), self.inputs.len());
+
+        let mut values = vec![0.0; self.size];
+
+        for (&index, &input) in self.inputs.iter().zip(inputs) {
+            values[index] = input;
+        }
+
+        for node in &self.nodes {
+            let mut output = node
+                .incoming
+                .iter()
+                .map(|&(from, weight)| values[from] * weight)
+                .sum::<f32>();
+
+            output += node.bias;
+
+            values[node.index] = node.activation.apply(output);
+        }
+
+        self.outputs.iter().map(|&index| values[index]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn propogate() {
+        let node = |id, kind, bias| NodeGene { id, kind, bias, activation: Activation::Identity };
+        let connection = |innovation, from, to, weight, enabled| ConnectionGene { innovation, from, to, weight, enabled };
+
+        // Inputs 0 and 1, hidden node 3 feeding output 2, plus a disabled direct connection.
+        let genome = Genome::from_genes(
+            vec![node(0, NodeKind::Input, 0.0), node(1, NodeKind::Input, 0.0), node(2, NodeKind::Output, 0.5), node(3, NodeKind::Hidden, -1.0)],
+            vec![
+                connection(0, 0, 2, 10.0, false),
+                connection(1, 1, 2, 2.0, true),
+                connection(2, 0, 3, 3.0, true),
+                connection(3, 3, 2, 0.5, true),
+            ],
+        );
+
+        let outputs = genome.network().propogate(&[1.0, 2.0]);
+
+        assert_relative_eq!(outputs.as_slice(), [2.0 * 2.0 + 0.5 * (3.0 - 1.0) + 0.5].as_ref());
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+/// Hands out node ids and innovation numbers, so that the same structural mutation gets the same
+/// numbers in every genome.
+#[derive(Clone, Debug, Default)]
+pub struct Innovations {
+    next_node: usize,
+    next_innovation: usize,
+    connections: HashMap<(usize, usize), usize>,
+    splits: HashMap<usize, usize>,
+}
+
+impl Innovations {
+    pub fn new(nodes: usize) -> Self {
+        Self { next_node: nodes, ..Self::default() }
+    }
+
+    pub fn connection(&mut self, from: usize, to: usize) -> usize {
+        *self.connections.entry((from, to)).or_insert_with(|| {
+            self.next_innovation += 1;
+            self.next_innovation - 1
+        })
+    }
+
+    /// Id of the node that splits the connection with the given innovation number.
+    pub fn split(&mut self, innovation: usize) -> usize {
+        *self.splits.entry(innovation).or_insert_with(|| {
+            self.next_node += 1;
+            self.next_node - 1
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn innovations() {
+        let mut innovations = Innovations::new(3);
+
+        assert_eq!(innovations.connection(0, 2), 0);
+        assert_eq!(innovations.connection(1, 2), 1);
+        assert_eq!(innovations.connection(0, 2), 0);
+
+        assert_eq!(innovations.split(1), 3);
+        assert_eq!(innovations.split(0), 4);
+        assert_eq!(innovations.split(1), 3);
+    }
+}
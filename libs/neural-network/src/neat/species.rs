@@ -0,0 +1,28 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Species {
+    pub(super) id: usize,
+    pub(super) representative: Genome,
+    pub(super) members: Vec<usize>,
+}
+
+impl Species {
+    pub(super) fn new(id: usize, representative: Genome, member: usize) -> Self {
+        Self { id, representative, members: vec![member] }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn representative(&self) -> &Genome {
+        &self.representative
+    }
+
+    /// Indices of this species' members in the population passed to the last `Neat::evolve`.
+    pub fn members(&self) -> &[usize] {
+        &self.members
+    }
+}
@@ -0,0 +1,26 @@
+mod adam;
+mod sgd;
+
+pub use self::{adam::*, sgd::*};
+use crate::NnError;
+
+pub trait Optimizer {
+    fn step(&mut self, weights: &mut dyn Iterator<Item = &mut f32>, gradients: &[f32]) {
+        self.try_step(weights, gradients).unwrap_or_else(|err| panic!("{}", err));
+    }
+
+    /// Updates `weights` in place, given gradients laid out like `Network::weights()`;
+    /// leaves them untouched if the counts differ.
+    fn try_step(&mut self, weights: &mut dyn Iterator<Item = &mut f32>, gradients: &[f32]) -> Result<(), NnError>;
+}
+
+/// Gathers `weights` up front, so a count mismatch is caught before any of them change.
+fn collect<'a>(weights: &mut dyn Iterator<Item = &'a mut f32>, gradients: &[f32]) -> Result<Vec<&'a mut f32>, NnError> {
+    let weights: Vec<_> = weights.collect();
+
+    if weights.len() != gradients.len() {
+        return Err(NnError::ParameterCount { expected: weights.len(), actual: gradients.len() });
+    }
+
+    Ok(weights)
+}
@@ -0,0 +1,78 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+pub struct Adam {
+    learning_rate: f32,
+    beta1: f32,
+    beta2: f32,
+    epsilon: f32,
+    moments: Vec<(f32, f32)>,
+    steps: i32,
+}
+
+impl Adam {
+    pub fn new(learning_rate: f32) -> Self {
+        assert!(learning_rate > 0.0);
+
+        Self {
+            learning_rate,
+            beta1: 0.9,
+            beta2: 0.999,
+            epsilon: 1e-8,
+            moments: Vec::new(),
+            steps: 0,
+        }
+    }
+
+    pub fn with_betas(mut self, beta1: f32, beta2: f32) -> Self {
+        assert!((0.0..1.0).contains(&beta1));
+        assert!((0.0..1.0).contains(&beta2));
+
+        self.beta1 = beta1;
+        self.beta2 = beta2;
+        self
+    }
+}
+
+impl Optimizer for Adam {
+    fn try_step(&mut self, weights: &mut dyn Iterator<Item = &mut f32>, gradients: &[f32]) -> Result<(), NnError> {
+        let weights = super::collect(weights, gradients)?;
+
+        self.moments.resize(gradients.len(), (0.0, 0.0));
+        self.steps += 1;
+
+        let correction1 = 1.0 - self.beta1.powi(self.steps);
+        let correction2 = 1.0 - self.beta2.powi(self.steps);
+
+        for ((weight, (m, v)), &gradient) in weights.into_iter().zip(&mut self.moments).zip(gradients) {
+            *m = self.beta1 * *m + (1.0 - self.beta1) * gradient;
+            *v = self.beta2 * *v + (1.0 - self.beta2) * gradient * gradient;
+
+            *weight -= self.learning_rate * (*m / correction1) / ((*v / correction2).sqrt() + self.epsilon);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn step() {
+        let mut weights = [1.0, -1.0, 0.0];
+        let mut adam = Adam::new(0.1);
+
+        adam.step(&mut weights.iter_mut(), &[0.5, -20.0, 0.0]);
+
+        assert_relative_eq!(weights.as_ref(), [0.9, -0.9, 0.0].as_ref(), epsilon = 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "got 2 gradients for 3 weights")]
+    fn step_with_missing_gradients() {
+        Adam::new(0.1).step(&mut [1.0, -1.0, 0.0].iter_mut(), &[0.5, -20.0]);
+    }
+}
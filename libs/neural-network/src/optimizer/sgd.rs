@@ -0,0 +1,70 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+pub struct Sgd {
+    learning_rate: f32,
+    momentum: f32,
+    velocity: Vec<f32>,
+}
+
+impl Sgd {
+    pub fn new(learning_rate: f32) -> Self {
+        assert!(learning_rate > 0.0);
+
+        Self { learning_rate, momentum: 0.0, velocity: Vec::new() }
+    }
+
+    pub fn with_momentum(mut self, momentum: f32) -> Self {
+        assert!((0.0..1.0).contains(&momentum));
+
+        self.momentum = momentum;
+        self
+    }
+}
+
+impl Optimizer for Sgd {
+    fn try_step(&mut self, weights: &mut dyn Iterator<Item = &mut f32>, gradients: &[f32]) -> Result<(), NnError> {
+        let weights = super::collect(weights, gradients)?;
+
+        self.velocity.resize(gradients.len(), 0.0);
+
+        for ((weight, velocity), gradient) in weights.into_iter().zip(&mut self.velocity).zip(gradients) {
+            *velocity = self.momentum * *velocity - self.learning_rate * gradient;
+            *weight += *velocity;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn step() {
+        let mut weights = [1.0, -1.0];
+        let mut sgd = Sgd::new(0.1).with_momentum(0.5);
+
+        sgd.step(&mut weights.iter_mut(), &[1.0, -2.0]);
+        assert_relative_eq!(weights.as_ref(), [0.9, -0.8].as_ref());
+
+        sgd.step(&mut weights.iter_mut(), &[1.0, -2.0]);
+        assert_relative_eq!(weights.as_ref(), [0.75, -0.5].as_ref());
+    }
+
+    #[test]
+    #[should_panic(expected = "got 3 gradients for 2 weights")]
+    fn step_with_extra_gradients() {
+        let mut weights = [1.0, -1.0];
+
+        assert_eq!(
+            Sgd::new(0.1).try_step(&mut weights.iter_mut(), &[1.0, -2.0, 3.0]),
+            Err(NnError::ParameterCount { expected: 2, actual: 3 }),
+        );
+        assert_eq!(weights, [1.0, -1.0]);
+
+        Sgd::new(0.1).step(&mut weights.iter_mut(), &[1.0, -2.0, 3.0]);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::*;
+use std::mem;
+
+/// A dense network with int8 weights, each layer sharing one scale factor. Inputs are
+/// quantized on the fly per layer, so the weighted sums run in integer arithmetic.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "QuantizedModel"))]
+pub struct QuantizedNetwork {
+    layers: Vec<QuantizedLayer>,
+}
+
+/// Deserialized form of a `QuantizedNetwork`, checked before it's used.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct QuantizedModel {
+    layers: Vec<QuantizedLayer>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct QuantizedLayer {
+    input_size: usize,
+    activation: Activation,
+    /// Each weight is approximately `scale * weights[i]`.
+    scale: f32,
+    weights: Vec<i8>,
+    biases: Vec<f32>,
+}
+
+/// How far a `QuantizedNetwork`'s outputs stray from the network it was made from.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Accuracy {
+    pub max_error: f32,
+    pub mean_error: f32,
+}
+
+impl<F: Float> Network<F> {
+    pub fn quantize(&self) -> QuantizedNetwork {
+        self.try_quantize().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_quantize(&self) -> Result<QuantizedNetwork, NnError> {
+        if let Some(layer) = self.layers.iter().position(|layer| layer.kind.is_recurrent()) {
+            return Err(NnError::RecurrentLayer { layer: layer + 1 });
+        }
+
+        let layers = self
+            .layers
+            .iter()
+            .map(|layer| {
+                let rows = layer.weights.chunks_exact(layer.input_size + 1);
+                let biases = rows.clone().map(|row| row[0].to_f32()).collect();
+                let inputs: Vec<f32> = rows.flat_map(|row| &row[1..]).map(|weight| weight.to_f32()).collect();
+                let mut weights = Vec::new();
+                let scale = quantize(&inputs, &mut weights);
+
+                QuantizedLayer { input_size: layer.input_size, activation: layer.activation, scale, weights, biases }
+            })
+            .collect();
+
+        Ok(QuantizedNetwork { layers })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<QuantizedModel> for QuantizedNetwork {
+    type Error = NnError;
+
+    fn try_from(model: QuantizedModel) -> Result<Self, Self::Error> {
+        if model.layers.is_empty() {
+            return Err(NnError::TooFewLayers { actual: 0 });
+        }
+
+        for (id, layer) in model.layers.iter().enumerate() {
+            if layer.input_size == 0 {
+                return Err(NnError::EmptyLayer { layer: id });
+            }
+
+            if layer.biases.is_empty() {
+                return Err(NnError::EmptyLayer { layer: id + 1 });
+            }
+
+            if let Some(previous) = id.checked_sub(1).map(|id| &model.layers[id]) {
+                if layer.input_size != previous.biases.len() {
+                    return Err(NnError::LayerMismatch {
+                        layer: id + 1,
+                        expected: previous.biases.len(),
+                        actual: layer.input_size,
+                    });
+                }
+            }
+
+            let expected = layer.biases.len() * layer.input_size;
+            let actual = layer.weights.len();
+
+            if actual < expected {
+                return Err(NnError::NotEnoughWeights { expected, actual });
+            }
+
+            if actual > expected {
+                return Err(NnError::TooManyWeights { expected, actual });
+            }
+        }
+
+        Ok(Self { layers: model.layers })
+    }
+}
+
+impl QuantizedNetwork {
+    pub fn topology(&self) -> Vec<LayerTopology> {
+        let input = LayerTopology::new(self.layers[0].input_size);
+        let layers = self.layers.iter().map(|layer| LayerTopology::new(layer.biases.len()).with_activation(layer.activation));
+
+        std::iter::once(input).chain(layers).collect()
+    }
+
+    pub fn propogate(&self, inputs: Vec<f32>) -> Vec<f32> {
+        self.try_propogate(inputs).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_propogate(&self, inputs: Vec<f32>) -> Result<Vec<f32>, NnError> {
+        Ok(self.try_propogate_with(&inputs, &mut Scratch::default())?.to_vec())
+    }
+
+    pub fn propogate_with<'a>(&self, inputs: &[f32], scratch: &'a mut Scratch) -> &'a [f32] {
+        self.try_propogate_with(inputs, scratch).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_propogate_with<'a>(&self, inputs: &[f32], scratch: &'a mut Scratch) -> Result<&'a [f32], NnError> {
+        let Scratch { inputs: layer_inputs, outputs, quantized, .. } = scratch;
+        let (first, rest) = self.layers.split_first().expect("network has no layers");
+
+        if inputs.len() != first.input_size {
+            return Err(NnError::InputLength { expected: first.input_size, actual: inputs.len() });
+        }
+
+        first.propogate(inputs, quantized, outputs);
+
+        for layer in rest {
+            mem::swap(layer_inputs, outputs);
+            layer.propogate(layer_inputs, quantized, outputs);
+        }
+
+        Ok(outputs)
+    }
+
+    /// Compares the outputs of both networks over `samples`.
+    pub fn accuracy<F: Float>(&self, network: &Network<F>, samples: &[Vec<F>]) -> Accuracy {
+        self.try_accuracy(network, samples).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_accuracy<F: Float>(&self, network: &Network<F>, samples: &[Vec<F>]) -> Result<Accuracy, NnError> {
+        if self.topology() != network.topology() {
+            return Err(NnError::TopologyMismatch);
+        }
+
+        let mut network_scratch = Scratch::default();
+        let mut scratch = Scratch::default();
+        let mut inputs = Vec::new();
+        let mut accuracy = Accuracy::default();
+        let mut count = 0;
+
+        for sample in samples {
+            let expected = network.try_propogate_with(sample, &mut network_scratch)?;
+
+            inputs.clear();
+            inputs.extend(sample.iter().map(|input| input.to_f32()));
+
+            for (actual, expected) in self.try_propogate_with(&inputs, &mut scratch)?.iter().zip(expected) {
+                let error = (actual - expected.to_f32()).abs();
+
+                accuracy.max_error = accuracy.max_error.max(error);
+                accuracy.mean_error += error;
+                count += 1;
+            }
+        }
+
+        if count > 0 {
+            accuracy.mean_error /= count as f32;
+        }
+
+        Ok(accuracy)
+    }
+}
+
+impl QuantizedLayer {
+    fn propogate(&self, inputs: &[f32], quantized: &mut Vec<i8>, outputs: &mut Vec<f32>) {
+        let scale = self.scale * quantize(inputs, quantized);
+
+        outputs.clear();
+        outputs.extend(self.weights.chunks_exact(self.input_size).zip(&self.biases).map(|(row, bias)| {
+            let sum: i32 = row.iter().zip(quantized.iter()).map(|(&weight, &input)| weight as i32 * input as i32).sum();
+
+            self.activation.apply(bias + sum as f32 * scale)
+        }));
+    }
+}
+
+/// Symmetrically maps `values` onto -127..=127, returning the scale to multiply them back by.
+fn quantize(values: &[f32], quantized: &mut Vec<i8>) -> f32 {
+    let max = values.iter().fold(0.0_f32, |max, value| max.max(value.abs()));
+    let scale = if max > 0.0 { max / 127.0 } else { 1.0 };
+
+    quantized.clear();
+    quantized.extend(values.iter().map(|value| (value / scale).round() as i8));
+
+    scale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn quantize() {
+        let network = Network::from_weights(
+            &[LayerTopology::new(2), LayerTopology::new(1).with_activation(Activation::Identity)],
+            vec![0.5, 1.0, -0.5],
+        );
+        let quantized = network.quantize();
+
+        assert_eq!(quantized.layers[0].weights, vec![127, -64]);
+        assert_eq!(quantized.topology(), network.topology());
+        assert_relative_eq!(quantized.propogate(vec![1.0, 0.0]).as_slice(), [1.5].as_ref());
+
+        let accuracy = quantized.accuracy(&network, &[vec![1.0, 0.0], vec![0.0, 1.0]]);
+
+        assert_relative_eq!(accuracy.max_error, 0.5 / 127.0);
+        assert_relative_eq!(accuracy.mean_error, 0.25 / 127.0);
+    }
+
+    #[test]
+    fn quantize_random() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let layers = [
+            LayerTopology::new(8),
+            LayerTopology::new(16),
+            LayerTopology::new(4).with_activation(Activation::Tanh),
+        ];
+        let network = Network::random_with(&mut rng, &layers, InitOptions::new(Initializer::He, Initializer::Zeros));
+        let quantized = network.quantize();
+        let samples: Vec<Vec<f32>> = (0..32).map(|_| (0..8).map(|_| rng.gen_range(-1.0..=1.0)).collect()).collect();
+
+        let accuracy = quantized.accuracy(&network, &samples);
+
+        assert!(accuracy.max_error < 0.05, "{:?}", accuracy);
+        assert!(accuracy.mean_error < 0.01, "{:?}", accuracy);
+        assert_eq!(
+            quantized.try_propogate(vec![0.0; 3]).err(),
+            Some(NnError::InputLength { expected: 8, actual: 3 }),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "layer 1 is recurrent")]
+    fn quantize_recurrent() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let network = Network::random(&mut rng, &[LayerTopology::new(2), LayerTopology::new(2).with_kind(LayerKind::Elman)]);
+
+        assert_eq!(network.try_quantize().err(), Some(NnError::RecurrentLayer { layer: 1 }));
+
+        network.quantize();
+    }
+
+    #[test]
+    fn quantize_f64() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let layers = [LayerTopology::new(4), LayerTopology::new(3).with_activation(Activation::Identity)];
+        let network = Network::<f64>::random_in(&mut rng, &layers, InitOptions::default());
+        let samples: Vec<Vec<f64>> = (0..8).map(|_| (0..4).map(|_| rng.gen_range(-1.0..=1.0)).collect()).collect();
+
+        let accuracy = network.quantize().accuracy(&network, &samples);
+
+        assert!(accuracy.max_error < 0.05, "{:?}", accuracy);
+    }
+
+    #[test]
+    fn accuracy_errors() {
+        let network = Network::from_weights(&[LayerTopology::new(1), LayerTopology::new(1)], vec![0.0, 1.0]);
+        let other = Network::from_weights(&[LayerTopology::new(2), LayerTopology::new(1)], vec![0.0, 1.0, 1.0]);
+        let quantized = network.quantize();
+
+        assert_eq!(quantized.try_accuracy(&other, &[]).err(), Some(NnError::TopologyMismatch));
+        assert_eq!(
+            quantized.try_accuracy(&network, &[vec![1.0, 2.0]]).err(),
+            Some(NnError::InputLength { expected: 1, actual: 2 }),
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates() {
+        let network = Network::from_weights(&[LayerTopology::new(2), LayerTopology::new(1)], vec![0.5, 1.0, -0.5]);
+        let json = serde_json::to_string(&network.quantize()).unwrap();
+        let quantized: QuantizedNetwork = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(quantized.topology(), network.topology());
+
+        let layer = |input_size: usize, weights: &str, biases: &str| {
+            format!(
+                r#"{{"input_size":{},"activation":"ReLU","scale":1.0,"weights":{},"biases":{}}}"#,
+                input_size, weights, biases,
+            )
+        };
+
+        for (layers, error) in [
+            (vec![], NnError::TooFewLayers { actual: 0 }),
+            (vec![layer(0, "[]", "[0.0]")], NnError::EmptyLayer { layer: 0 }),
+            (vec![layer(2, "[]", "[]")], NnError::EmptyLayer { layer: 1 }),
+            (vec![layer(2, "[1]", "[0.0]")], NnError::NotEnoughWeights { expected: 2, actual: 1 }),
+            (vec![layer(2, "[1,2,3]", "[0.0]")], NnError::TooManyWeights { expected: 2, actual: 3 }),
+            (
+                vec![layer(2, "[1,2]", "[0.0]"), layer(2, "[1,2]", "[0.0]")],
+                NnError::LayerMismatch { layer: 2, expected: 1, actual: 2 },
+            ),
+        ] {
+            let json = format!(r#"{{"layers":[{}]}}"#, layers.join(","));
+            let err = serde_json::from_str::<QuantizedNetwork>(&json).unwrap_err();
+
+            assert!(err.to_string().starts_with(&error.to_string()), "{}", err);
+        }
+    }
+}
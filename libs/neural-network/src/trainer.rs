@@ -0,0 +1,157 @@
+use crate::*;
+use rand::seq::SliceRandom;
+
+/// Trains `f32` networks; see `Network::gradients`.
+pub struct Trainer {
+    optimizer: Box<dyn Optimizer>,
+    loss: Loss,
+    gradients: Vec<f32>,
+}
+
+impl Trainer {
+    pub fn new(optimizer: impl Optimizer + 'static, loss: Loss) -> Self {
+        Self { optimizer: Box::new(optimizer), loss, gradients: Vec::new() }
+    }
+
+    /// Takes a single optimizer step on the averaged gradient of `batch`, returning its mean loss.
+    pub fn train_batch(&mut self, network: &mut Network, batch: &[(Vec<f32>, Vec<f32>)]) -> f32 {
+        self.try_train_batch(network, batch).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Leaves `network` untouched if any sample doesn't fit it.
+    pub fn try_train_batch(&mut self, network: &mut Network, batch: &[(Vec<f32>, Vec<f32>)]) -> Result<f32, NnError> {
+        self.try_step(network, batch.iter())
+    }
+
+    /// Runs one shuffled pass over `samples` in mini-batches, returning the mean loss per sample.
+    pub fn train_epoch(
+        &mut self,
+        rng: &mut dyn RngCore,
+        network: &mut Network,
+        samples: &[(Vec<f32>, Vec<f32>)],
+        batch_size: usize,
+    ) -> f32 {
+        self.try_train_epoch(rng, network, samples, batch_size).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Stops at the first batch holding a sample that doesn't fit `network`;
+    /// the batches before it have already been applied.
+    pub fn try_train_epoch(
+        &mut self,
+        rng: &mut dyn RngCore,
+        network: &mut Network,
+        samples: &[(Vec<f32>, Vec<f32>)],
+        batch_size: usize,
+    ) -> Result<f32, NnError> {
+        assert!(batch_size > 0);
+        assert!(!samples.is_empty());
+
+        let mut order: Vec<_> = (0..samples.len()).collect();
+        order.shuffle(rng);
+
+        let mut total = 0.0;
+
+        for batch in order.chunks(batch_size) {
+            total += self.try_step(network, batch.iter().map(|&i| &samples[i]))? * batch.len() as f32;
+        }
+
+        Ok(total / samples.len() as f32)
+    }
+
+    fn try_step<'a>(
+        &mut self,
+        network: &mut Network,
+        batch: impl ExactSizeIterator<Item = &'a (Vec<f32>, Vec<f32>)>,
+    ) -> Result<f32, NnError> {
+        assert!(batch.len() > 0);
+
+        let len = batch.len() as f32;
+
+        self.gradients.clear();
+        self.gradients.resize(network.parameter_count(), 0.0);
+
+        let mut loss = 0.0;
+
+        for (inputs, targets) in batch {
+            loss += network.try_accumulate_gradients(inputs, targets, self.loss, &mut self.gradients)?;
+        }
+
+        for gradient in &mut self.gradients {
+            *gradient /= len;
+        }
+
+        self.optimizer.try_step(&mut network.weights_mut(), &self.gradients)?;
+
+        Ok(loss / len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn xor() -> Vec<(Vec<f32>, Vec<f32>)> {
+        vec![
+            (vec![0.0, 0.0], vec![0.0]),
+            (vec![0.0, 1.0], vec![1.0]),
+            (vec![1.0, 0.0], vec![1.0]),
+            (vec![1.0, 1.0], vec![0.0]),
+        ]
+    }
+
+    fn train(optimizer: impl Optimizer + 'static, loss: Loss) -> (f32, f32) {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut network = Network::random(&mut rng, &[
+            LayerTopology::new(2),
+            LayerTopology::new(4).with_activation(Activation::Tanh),
+            LayerTopology::new(1).with_activation(Activation::Sigmoid),
+        ]);
+        let mut trainer = Trainer::new(optimizer, loss);
+        let samples = xor();
+
+        let initial = trainer.train_epoch(&mut rng, &mut network, &samples, 4);
+
+        for _ in 0..2000 {
+            trainer.train_epoch(&mut rng, &mut network, &samples, 2);
+        }
+
+        let last = samples
+            .iter()
+            .map(|(inputs, targets)| loss.loss(&network.propogate(inputs.clone()), targets))
+            .sum::<f32>() / samples.len() as f32;
+
+        (initial, last)
+    }
+
+    #[test]
+    fn sgd_learns_xor() {
+        let (initial, last) = train(Sgd::new(0.5).with_momentum(0.9), Loss::MeanSquaredError);
+
+        assert!(last < initial / 10.0, "{} -> {}", initial, last);
+    }
+
+    #[test]
+    fn train_batch_with_wrong_targets() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut network = Network::random(&mut rng, &[LayerTopology::new(2), LayerTopology::new(1)]);
+        let weights: Vec<_> = network.weights().collect();
+        let mut trainer = Trainer::new(Sgd::new(0.5), Loss::MeanSquaredError);
+
+        let batch = [(vec![0.0, 1.0], vec![1.0]), (vec![1.0, 1.0], vec![0.0, 1.0])];
+
+        assert_eq!(
+            trainer.try_train_batch(&mut network, &batch).err(),
+            Some(NnError::TargetLength { expected: 1, actual: 2 }),
+        );
+        assert_eq!(network.weights().collect::<Vec<_>>(), weights);
+    }
+
+    #[test]
+    fn adam_learns_xor() {
+        let (initial, last) = train(Adam::new(0.05), Loss::CrossEntropy);
+
+        assert!(last < initial / 10.0, "{} -> {}", initial, last);
+    }
+}
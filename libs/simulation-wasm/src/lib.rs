@@ -1,4 +1,3 @@
-use wasm_bindgen::prelude::*;
 use lib_simulation as sim;
 use rand::prelude::*;
 use wasm_bindgen::prelude::*;
@@ -23,6 +22,10 @@ impl Simulation {
         World::from(self.sim.world())
     }
 
+    pub fn brain_svg(&self, agent: usize) -> Option<String> {
+        self.sim.brain_svg(agent)
+    }
+
     pub fn step(&mut self) {
         self.sim.step(&mut self.rng);
     }
@@ -39,6 +42,12 @@ impl Simulation {
     }
 }
 
+impl Default for Simulation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
 pub struct World {
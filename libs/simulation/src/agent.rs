@@ -30,10 +30,14 @@ impl Agent {
     }
 
     pub(crate) fn from_chromosome(chromosome: ga::Chromosome, rng: &mut dyn RngCore) -> Self {
+        Self::try_from_chromosome(chromosome, rng).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub(crate) fn try_from_chromosome(chromosome: ga::Chromosome, rng: &mut dyn RngCore) -> Result<Self, nn::NnError> {
         let eye = Eye::default();
-        let brain = Brain::from_chromosome(chromosome, &eye);
+        let brain = Brain::try_from_chromosome(chromosome, &eye)?;
 
-        Self::new(eye, brain, rng)
+        Ok(Self::new(eye, brain, rng))
     }
 
     pub(crate) fn as_chromosome(&self) -> ga::Chromosome {
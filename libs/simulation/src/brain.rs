@@ -1,4 +1,5 @@
 use crate::*;
+use std::cmp::Ordering;
 
 #[derive(Debug, Clone)]
 pub struct Brain {
@@ -7,28 +8,40 @@ pub struct Brain {
 
 impl Brain {
     pub fn random(rng: &mut dyn RngCore, eye: &Eye) -> Self {
-        Self {nn: nn::Network::random(rng, &Self::topology(eye))}
+        let options = nn::InitOptions::new(nn::Initializer::He, nn::Initializer::Zeros);
+
+        Self {nn: nn::Network::random_with(rng, &Self::topology(eye), options)}
+    }
+
+    pub(crate) fn try_from_chromosome(chromosomes: ga::Chromosome, eye: &Eye) -> Result<Self, nn::NnError> {
+        Ok(Self { nn: nn::Network::try_from_weights(&Self::topology(eye), chromosomes)? })
     }
 
-    pub(crate) fn from_chromosome(chromosomes: ga::Chromosome, eye: &Eye) -> Self {
-        Self { nn: nn::Network::from_weights(&Self::topology(eye), chromosomes) }
+    /// Checks `chromosome` has the right length for a brain, without building one.
+    pub(crate) fn check_chromosome(chromosome: &ga::Chromosome, eye: &Eye) -> Result<(), nn::NnError> {
+        let expected = Self::chromosome_len(eye);
+        let actual = chromosome.len();
+
+        match actual.cmp(&expected) {
+            Ordering::Less => Err(nn::NnError::NotEnoughWeights { expected, actual }),
+            Ordering::Greater => Err(nn::NnError::TooManyWeights { expected, actual }),
+            Ordering::Equal => Ok(()),
+        }
     }
 
     pub(crate) fn as_chromosome(&self) -> ga::Chromosome {
         self.nn.weights().collect()
     }
 
+    pub(crate) fn chromosome_len(eye: &Eye) -> usize {
+        nn::Network::parameter_count_for(&Self::topology(eye)).expect("brain topology is valid")
+    }
+
     fn topology(eye: &Eye) -> [nn::LayerTopology; 3] {
         [
-            nn::LayerTopology {
-                neurons: eye.cells(),
-            },
-            nn::LayerTopology {
-                neurons: 2 * eye.cells(),
-            },
-            nn::LayerTopology {
-                neurons: 2,
-            }
+            nn::LayerTopology::new(eye.cells()),
+            nn::LayerTopology::new(2 * eye.cells()),
+            nn::LayerTopology::new(2).with_activation(nn::Activation::Tanh),
         ]
     }
 }
\ No newline at end of file
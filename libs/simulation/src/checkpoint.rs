@@ -0,0 +1,80 @@
+use crate::*;
+use std::io::{self, Read, Write};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub(crate) config: Config,
+    pub(crate) agents: Vec<AgentState>,
+    pub(crate) foods: Vec<Food>,
+    pub(crate) age: usize,
+    pub(crate) generation: usize,
+    pub(crate) history: Vec<ga::Statistics>,
+    pub(crate) hall_of_fame: ga::HallOfFame,
+    pub(crate) rng: ChaCha8Rng,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct AgentState {
+    position: na::Point2<f32>,
+    rotation: na::Rotation2<f32>,
+    speed: f32,
+    satiation: usize,
+    pub(crate) chromosome: ga::Chromosome,
+}
+
+impl Checkpoint {
+    pub fn save(&self, writer: impl Write) -> io::Result<()> {
+        serde_json::to_writer(writer, self)?;
+
+        Ok(())
+    }
+
+    pub fn load(reader: impl Read) -> io::Result<Self> {
+        let checkpoint: Self = serde_json::from_reader(reader)?;
+
+        checkpoint.config.validate().map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        checkpoint.validate().map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        Ok(checkpoint)
+    }
+
+    /// Checks every agent's and hall of fame entry's chromosome fits a brain.
+    pub(crate) fn validate(&self) -> Result<(), nn::NnError> {
+        let eye = Eye::default();
+        let agents = self.agents.iter().map(|agent| &agent.chromosome);
+        let hall_of_fame = self.hall_of_fame.entries().iter().map(|entry| &entry.chromosome);
+
+        agents.chain(hall_of_fame).try_for_each(|chromosome| Brain::check_chromosome(chromosome, &eye))
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+}
+
+impl AgentState {
+    pub(crate) fn from_agent(agent: &Agent) -> Self {
+        Self {
+            position: agent.position,
+            rotation: agent.rotation,
+            speed: agent.speed,
+            satiation: agent.satiation,
+            chromosome: agent.as_chromosome(),
+        }
+    }
+
+    pub(crate) fn try_into_agent(self) -> Result<Agent, nn::NnError> {
+        let eye = Eye::default();
+        let brain = Brain::try_from_chromosome(self.chromosome, &eye)?;
+
+        Ok(Agent {
+            position: self.position,
+            rotation: self.rotation,
+            speed: self.speed,
+            eye,
+            brain,
+            satiation: self.satiation,
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use crate::*;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Config {
+    pub selection: ga::RouletteWheelSelection,
+    pub crossover: ga::UniformCrossover,
+    pub mutation: ga::GaussianMutation,
+    pub bounds: ga::Bounds,
+    pub repair: ga::ReflectRepair,
+    /// Defaults to none, matching checkpoints from before immigration existed.
+    #[serde(default)]
+    pub immigrants: usize,
+    #[serde(default = "default_immigration_interval")]
+    pub immigration_interval: usize,
+}
+
+fn default_immigration_interval() -> usize {
+    IMMIGRATION_INTERVAL
+}
+
+impl Config {
+    /// Catches what the constructors would assert on, for configs that were deserialized.
+    pub(crate) fn validate(&self) -> Result<(), ga::GaError> {
+        if self.immigrants > 0 && self.immigration_interval == 0 {
+            return Err(ga::GaError::InvalidConfig("immigration_interval must be positive"));
+        }
+
+        self.bounds.try_check_len(Brain::chromosome_len(&Eye::default()))
+    }
+
+    pub(crate) fn genetic_algorithm(&self) -> ga::GeneticAlgorithm<ga::RouletteWheelSelection> {
+        let ga = ga::GeneticAlgorithm::new(
+            self.selection.clone(),
+            self.crossover.clone(),
+            self.mutation.clone(),
+        )
+        .with_bounds(self.bounds.clone())
+        .with_repair_method(self.repair.clone());
+
+        if self.immigrants == 0 {
+            return ga;
+        }
+
+        let genes = Brain::chromosome_len(&Eye::default());
+
+        ga.with_immigration(ga::RandomImmigrants::new(
+            ga::UniformInitialization::new(self.bounds.clone(), genes),
+            self.immigrants,
+            self.immigration_interval,
+        ))
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            selection: ga::RouletteWheelSelection,
+            crossover: ga::UniformCrossover,
+            mutation: ga::GaussianMutation::new(0.01, 0.3),
+            bounds: ga::Bounds::uniform(WEIGHT_MIN, WEIGHT_MAX),
+            repair: ga::ReflectRepair,
+            immigrants: IMMIGRANTS,
+            immigration_interval: IMMIGRATION_INTERVAL,
+        }
+    }
+}
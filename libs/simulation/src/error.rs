@@ -0,0 +1,31 @@
+use crate::*;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SimulationError {
+    Config(ga::GaError),
+    Brain(nn::NnError),
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Config(err) => write!(f, "{}", err),
+            Self::Brain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SimulationError {}
+
+impl From<ga::GaError> for SimulationError {
+    fn from(err: ga::GaError) -> Self {
+        Self::Config(err)
+    }
+}
+
+impl From<nn::NnError> for SimulationError {
+    fn from(err: nn::NnError) -> Self {
+        Self::Brain(err)
+    }
+}
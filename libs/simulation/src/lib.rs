@@ -2,6 +2,9 @@ mod agent;
 mod agent_individual;
 mod eye;
 mod brain;
+mod checkpoint;
+mod config;
+mod error;
 mod food;
 mod world;
 
@@ -9,6 +12,9 @@ pub use self::agent::*;
 pub use self::agent_individual::*;
 pub use self::eye::*;
 pub use self::brain::*;
+pub use self::checkpoint::*;
+pub use self::config::*;
+pub use self::error::*;
 pub use self::food::*;
 pub use self::world::*;
 pub use self::nn::*;
@@ -17,6 +23,8 @@ use lib_genetic_algorithm as ga;
 use lib_neural_network as nn;
 use nalgebra as na;
 use rand::{Rng, RngCore};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
 use std::f32::consts::FRAC_PI_4;
 
 const SPEED_MIN: f32 = 0.001;
@@ -26,25 +34,100 @@ const ROTATION_ACCEL: f32 = FRAC_PI_4;
 
 const GENERATION_LIMIT: usize = 2500;
 
+const WEIGHT_MIN: f32 = -1.0;
+const WEIGHT_MAX: f32 = 1.0;
+
+const HALL_OF_FAME_SIZE: usize = 10;
+
+const IMMIGRANTS: usize = 2;
+const IMMIGRATION_INTERVAL: usize = 10;
+
 pub struct Simulation {
     world: World,
+    config: Config,
     ga: ga::GeneticAlgorithm<ga::RouletteWheelSelection>,
     age: usize,
+    generation: usize,
+    history: Vec<ga::Statistics>,
+    hall_of_fame: ga::HallOfFame,
+    /// Reused by every brain on every step, so propagation doesn't allocate.
+    scratch: nn::Scratch,
 }
 
 impl Simulation {
     pub fn random(rng: &mut dyn RngCore) -> Self {
+        Self::with_config(rng, Config::default())
+    }
+
+    pub fn with_config(rng: &mut dyn RngCore, config: Config) -> Self {
         let world = World::random(rng);
-        let ga = ga::GeneticAlgorithm::new(
-            ga::RouletteWheelSelection,
-            ga::UniformCrossover,
-            ga::GaussianMutation::new(0.01, 0.3),
-        );
+        let ga = config.genetic_algorithm();
 
         Self {
             world,
+            config,
             ga,
             age: 0,
+            generation: 0,
+            history: Vec::new(),
+            hall_of_fame: ga::HallOfFame::new(HALL_OF_FAME_SIZE),
+            scratch: nn::Scratch::default(),
+        }
+    }
+
+    pub fn replay(rng: &mut dyn RngCore, chromosome: &ga::Chromosome) -> Self {
+        Self::try_replay(rng, chromosome).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_replay(rng: &mut dyn RngCore, chromosome: &ga::Chromosome) -> Result<Self, nn::NnError> {
+        Brain::check_chromosome(chromosome, &Eye::default())?;
+
+        let mut simulation = Self::random(rng);
+
+        for agent in &mut simulation.world.agents {
+            *agent = Agent::try_from_chromosome(chromosome.clone(), rng)?;
+        }
+
+        Ok(simulation)
+    }
+
+    pub fn resume(checkpoint: Checkpoint) -> (Self, ChaCha8Rng) {
+        Self::try_resume(checkpoint).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_resume(checkpoint: Checkpoint) -> Result<(Self, ChaCha8Rng), SimulationError> {
+        checkpoint.config.validate()?;
+        checkpoint.validate()?;
+
+        let world = World {
+            agents: checkpoint.agents.into_iter().map(AgentState::try_into_agent).collect::<Result<_, _>>()?,
+            foods: checkpoint.foods,
+        };
+
+        let simulation = Self {
+            world,
+            ga: checkpoint.config.genetic_algorithm(),
+            config: checkpoint.config,
+            age: checkpoint.age,
+            generation: checkpoint.generation,
+            history: checkpoint.history,
+            hall_of_fame: checkpoint.hall_of_fame,
+            scratch: nn::Scratch::default(),
+        };
+
+        Ok((simulation, checkpoint.rng))
+    }
+
+    pub fn checkpoint(&self, rng: &ChaCha8Rng) -> Checkpoint {
+        Checkpoint {
+            config: self.config.clone(),
+            agents: self.world.agents.iter().map(AgentState::from_agent).collect(),
+            foods: self.world.foods.clone(),
+            age: self.age,
+            generation: self.generation,
+            history: self.history.clone(),
+            hall_of_fame: self.hall_of_fame.clone(),
+            rng: rng.clone(),
         }
     }
 
@@ -52,12 +135,33 @@ impl Simulation {
         &self.world
     }
 
+    /// SVG of the given agent's brain, shaded by what it currently sees.
+    pub fn brain_svg(&self, agent: usize) -> Option<String> {
+        let agent = self.world.agents.get(agent)?;
+        let vision = agent.eye.process_vision(agent.position, agent.rotation, &self.world.foods);
+        let activations = agent.brain.nn.propogate_traced(vision);
+
+        Some(agent.brain.nn.to_svg(Some(&activations)))
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn history(&self) -> &[ga::Statistics] {
+        &self.history
+    }
+
+    pub fn hall_of_fame(&self) -> &ga::HallOfFame {
+        &self.hall_of_fame
+    }
+
     pub fn step(&mut self, rng: &mut dyn RngCore) -> Option<ga::Statistics> {
         self.process_collisions(rng);
         self.process_brains();
         self.process_movement();
 
-        self.age = self.age + 1;
+        self.age += 1;
 
         if self.age > GENERATION_LIMIT {
             Some(self.evolve(rng))
@@ -82,7 +186,7 @@ impl Simulation {
                 &self.world.foods,
             );
 
-            let response = agent.brain.nn.propogate(vision);
+            let response = agent.brain.nn.propogate_with(&vision, &mut self.scratch);
 
             let speed = response[0].clamp(-SPEED_ACCEL, SPEED_ACCEL);
             let rotation = response[1].clamp(-ROTATION_ACCEL, ROTATION_ACCEL);
@@ -126,9 +230,12 @@ impl Simulation {
             .map(AgentIndividual::from_agent)
             .collect();
 
+        self.hall_of_fame.update(&current_population, self.generation);
+
         let (evolved_population, stats) = self.ga.evolve(
             rng,
             &current_population,
+            self.generation,
         );
 
         self.world.agents = evolved_population
@@ -140,6 +247,9 @@ impl Simulation {
             food.position = rng.gen();
         }
 
+        self.generation += 1;
+        self.history.push(stats.clone());
+
         stats
     }
 }
@@ -148,9 +258,7 @@ impl Simulation {
 #[cfg(test)]
 mod tests {
     use rand::SeedableRng;
-    use rand_chacha::ChaCha8Rng;
     use super::*;
-    use crate::World;
 
 
     #[test]
@@ -167,4 +275,175 @@ mod tests {
 
         assert_ne!(agent_before.position.x, agent_after.position.x);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn chromosome_len() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let agent = Agent::random(&mut rng);
+
+        assert_eq!(agent.as_chromosome().len(), Brain::chromosome_len(&agent.eye));
+    }
+
+    #[test]
+    fn hall_of_fame_and_replay() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random(&mut rng);
+
+        let stats = simulation.train(&mut rng);
+        let best = simulation.hall_of_fame().best().unwrap().clone();
+
+        assert_eq!(best.generation, 0);
+        assert_eq!(best.fitness, stats.max_fitness);
+
+        let replay = Simulation::replay(&mut rng, &best.chromosome);
+
+        assert!(replay
+            .world()
+            .agents()
+            .iter()
+            .all(|agent| agent.as_chromosome().iter().eq(best.chromosome.iter())));
+    }
+
+    #[test]
+    fn checkpoint_and_resume() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random(&mut rng);
+
+        simulation.train(&mut rng);
+
+        for _ in 0..100 {
+            simulation.step(&mut rng);
+        }
+
+        let mut file = Vec::new();
+        simulation.checkpoint(&rng).save(&mut file).unwrap();
+
+        let (mut resumed, mut resumed_rng) = Simulation::resume(Checkpoint::load(file.as_slice()).unwrap());
+
+        assert_eq!(resumed.generation(), 1);
+        assert_eq!(resumed.history().len(), 1);
+
+        let expected_stats = simulation.train(&mut rng);
+        let actual_stats = resumed.train(&mut resumed_rng);
+
+        assert_eq!(actual_stats.max_fitness, expected_stats.max_fitness);
+        assert_eq!(actual_stats.avg_fitness, expected_stats.avg_fitness);
+
+        let mut expected = Vec::new();
+        simulation.checkpoint(&rng).save(&mut expected).unwrap();
+
+        let mut actual = Vec::new();
+        resumed.checkpoint(&resumed_rng).save(&mut actual).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn load_corrupt_checkpoint() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut checkpoint = Simulation::random(&mut rng).checkpoint(&rng);
+
+        checkpoint.agents[0].chromosome = vec![0.0; 3].into_iter().collect();
+
+        let mut file = Vec::new();
+        checkpoint.save(&mut file).unwrap();
+
+        let err = Checkpoint::load(file.as_slice()).unwrap_err();
+
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("got not enough weights"));
+    }
+
+    #[test]
+    fn load_checkpoint_with_corrupt_hall_of_fame() {
+        use ga::Individual;
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut checkpoint = Simulation::random(&mut rng).checkpoint(&rng);
+
+        checkpoint.hall_of_fame.update(&[AgentIndividual::create(vec![0.0; 3].into_iter().collect())], 0);
+
+        assert_eq!(
+            Simulation::try_resume(checkpoint.clone()).err(),
+            Some(SimulationError::Brain(nn::NnError::NotEnoughWeights {
+                expected: Brain::chromosome_len(&Eye::default()),
+                actual: 3,
+            })),
+        );
+
+        let mut file = Vec::new();
+        checkpoint.save(&mut file).unwrap();
+
+        let err = Checkpoint::load(file.as_slice()).unwrap_err();
+
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("got not enough weights"));
+    }
+
+    #[test]
+    fn load_checkpoint_config() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let checkpoint = Simulation::random(&mut rng).checkpoint(&rng);
+        let mut json = serde_json::to_value(&checkpoint).unwrap();
+        let load = |json: &serde_json::Value| Checkpoint::load(json.to_string().as_bytes());
+
+        let config = json["config"].as_object_mut().unwrap();
+        config.remove("immigrants");
+        config.remove("immigration_interval");
+
+        let loaded = load(&json).unwrap();
+
+        assert_eq!((loaded.config.immigrants, loaded.config.immigration_interval), (0, IMMIGRATION_INTERVAL));
+
+        json["config"]["immigrants"] = 2.into();
+        json["config"]["immigration_interval"] = 0.into();
+
+        assert_eq!(load(&json).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+
+        json["config"]["immigration_interval"] = IMMIGRATION_INTERVAL.into();
+        json["config"]["bounds"] = serde_json::to_value(ga::Bounds::per_gene([(-1.0, 1.0); 3])).unwrap();
+
+        assert_eq!(load(&json).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn resume_checks_config() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut checkpoint = Simulation::random(&mut rng).checkpoint(&rng);
+
+        checkpoint.config.bounds = ga::Bounds::per_gene([(-1.0, 1.0); 3]);
+
+        assert_eq!(
+            Simulation::try_resume(checkpoint).err(),
+            Some(SimulationError::Config(ga::GaError::LengthMismatch {
+                expected: 3,
+                actual: Brain::chromosome_len(&Eye::default()),
+            })),
+        );
+    }
+
+    #[test]
+    fn replay_corrupt_chromosome() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let expected = Brain::chromosome_len(&Eye::default());
+        let chromosome = vec![0.0; expected + 1].into_iter().collect();
+
+        assert_eq!(
+            Simulation::try_replay(&mut rng, &chromosome).err(),
+            Some(nn::NnError::TooManyWeights { expected, actual: expected + 1 }),
+        );
+    }
+
+    #[test]
+    fn brain_svg() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let simulation = Simulation::random(&mut rng);
+        let svg = simulation.brain_svg(0).unwrap();
+
+        let eye = Eye::default();
+        let neurons = eye.cells() + 2 * eye.cells() + 2;
+
+        assert_eq!(svg.matches("<circle").count(), neurons);
+        assert!(simulation.brain_svg(simulation.world().agents().len()).is_none());
+    }
+}